@@ -115,6 +115,39 @@ pub enum FromSqlError {
 
     #[error("Unsupported operation.")]
     UnsupportedOperation,
+
+    #[error("Enum value {} has no label.", value)]
+    UnknownEnumValue { value: i16 },
+
+    #[error("Code {} has no mapping.", code)]
+    UnknownCode { code: u32 },
+
+    #[error("Non-finite float value.")]
+    NonFinite,
+
+    #[error("Expected a single character, got {}.", len)]
+    NotSingleChar { len: usize },
+
+    #[error("Index {} is out of bounds for a column of {} rows.", index, len)]
+    IndexOutOfBounds { index: usize, len: usize },
+
+    #[error("Invalid datetime format {:?}.", format)]
+    InvalidFormat { format: String },
+
+    #[error("Expected [{}; {}], got {} elements.", item, expected, actual)]
+    ArrayLength {
+        item: Cow<'static, str>,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("column \"{}\" row {}: {}", column, index, source)]
+    InColumn {
+        column: String,
+        index: usize,
+        #[source]
+        source: Box<FromSqlError>,
+    },
 }
 
 impl Error {
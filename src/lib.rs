@@ -16,9 +16,11 @@
 //! * Decimal(P, S)
 //! * Float32, Float64
 //! * String, FixedString(N)
-//! * UInt8, UInt16, UInt32, UInt64, Int8, Int16, Int32, Int64
+//! * UInt8, UInt16, UInt32, UInt64, UInt128, Int8, Int16, Int32, Int64, Int128
 //! * Nullable(T)
 //! * Array(UInt/Int/String/Date/DateTime)
+//! * Map(K, V)
+//! * LowCardinality(T)
 //! * IPv4/IPv6
 //! * UUID
 //!
@@ -66,6 +68,9 @@
 //! - `tokio_io` *(enabled by default)* — I/O based on [Tokio](https://tokio.rs/).
 //! - `async_std` — I/O based on [async-std](https://async.rs/) (doesn't work together with `tokio_io`).
 //! - `tls` — TLS support (allowed only with `tokio_io`).
+//! - `jiff` — `FromSql` for [jiff](https://docs.rs/jiff) `Timestamp`.
+//! - `fixed` — `FromSql` from `Decimal` for [fixed](https://docs.rs/fixed) `FixedI128`/`FixedU128`.
+//! - `serde_json` — conversion of values and columns to [serde_json](https://docs.rs/serde_json) `Value`.
 //!
 //! ### Example
 //!
@@ -108,7 +108,7 @@ fn put_param<K: ColumnType>(
     Ok(())
 }
 
-fn extract_timezone(value: &Value) -> Tz {
+pub(crate) fn extract_timezone(value: &Value) -> Tz {
     match value {
         Value::Date(_, tz) => *tz,
         Value::DateTime(_, tz) => *tz,
@@ -17,12 +17,12 @@ use crate::{
     errors::{Error, FromSqlError, Result},
     types::{
         column::{self, ArcColumnWrapper, Column, ColumnFrom},
-        FromSql, SqlType, ColumnType, Simple, Complex
+        FromSql, SqlType, ColumnType, Simple, Complex, Value
     },
 };
 
 use self::chunk_iterator::ChunkIterator;
-pub(crate) use self::row::BlockRef;
+pub(crate) use self::{builder::extract_timezone, row::BlockRef};
 pub use self::{
     block_info::BlockInfo,
     builder::{RCons, RNil, RowBuilder},
@@ -64,11 +64,13 @@ sliceable! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     i8: Int8,
     i16: Int16,
     i32: Int32,
-    i64: Int64
+    i64: Int64,
+    i128: Int128
 }
 
 /// Represents Clickhouse Block
@@ -220,7 +222,15 @@ impl<K: ColumnType> Block<K> {
         I: ColumnIdx + Copy,
     {
         let column_index = col.get_index(self.columns())?;
-        T::from_sql(self.columns[column_index].at(row))
+        let column = &self.columns[column_index];
+        T::from_sql(column.at(row)).map_err(|err| match err {
+            Error::FromSql(source) => Error::FromSql(FromSqlError::InColumn {
+                column: column.name().to_string(),
+                index: row,
+                source: Box::new(source),
+            }),
+            err => err,
+        })
     }
 
     /// Add new column into this block
@@ -271,6 +281,13 @@ impl<K: ColumnType> Block<K> {
         let column = &self.columns[column_index];
         Ok(column)
     }
+
+    /// Returns the values of the block row by row, e.g. for exporting it.
+    pub fn to_rows(&self) -> Result<Vec<Vec<Value>>> {
+        let columns: Vec<&dyn column::ColumnData> =
+            self.columns.iter().map(|column| column.data.as_ref() as _).collect();
+        column::transpose(&columns)
+    }
 }
 
 impl Block<Simple> {
@@ -488,6 +505,85 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_128_bit_columns() {
+        let expected = Block::<Simple>::new()
+            .column("i", vec![i128::MIN, 0, i128::MAX])
+            .column("u", vec![u128::MIN, 1, u128::MAX]);
+
+        let mut encoder = Encoder::new();
+        expected.write(&mut encoder, false);
+        let source = encoder.get_buffer();
+        let mut cursor = Cursor::new(&source[..]);
+        let actual = Block::load(&mut cursor, Tz::UTC, false).unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.get::<i128, _>(0, "i").unwrap(), i128::MIN);
+        assert_eq!(actual.get::<i128, _>(2, "i").unwrap(), i128::MAX);
+        assert_eq!(actual.get::<u128, _>(2, "u").unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn test_get_error_context() {
+        let block = Block::<Simple>::new().column("ts", vec!["a", "b"]);
+        let expected =
+            "From SQL error: `column \"ts\" row 1: SqlType::String cannot be cast to u32.`";
+
+        match block.get::<u32, _>(1, "ts") {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(format!("{}", e), expected),
+        }
+
+        let row = block.rows().nth(1).unwrap();
+        match row.get::<u32, _>("ts") {
+            Ok(_) => panic!("should fail"),
+            Err(Error::FromSql(FromSqlError::InColumn {
+                column,
+                index,
+                source,
+            })) => {
+                assert_eq!((column.as_str(), index), ("ts", 1));
+                assert!(matches!(*source, FromSqlError::InvalidType { .. }));
+            }
+            Err(e) => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn test_to_rows() {
+        let block = Block::<Simple>::new()
+            .column("id", vec![1_u32, 2])
+            .column("name", vec!["a", "b"]);
+
+        let rows = block.to_rows().unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::UInt32(1), Value::from("a")],
+                vec![Value::UInt32(2), Value::from("b")],
+            ]
+        );
+        assert!(Block::<Simple>::new().to_rows().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_array() {
+        let block = Block::<Simple>::new()
+            .column("ids", vec![vec![1_u32, 2, 3], vec![]])
+            .column("count", vec![3_u32, 0]);
+        let row = block.rows().next().unwrap();
+
+        assert_eq!(row.get_array::<u32, _>("ids").unwrap(), vec![1, 2, 3]);
+
+        match row.get_array::<u32, _>("count") {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                format!("{}", e),
+                "From SQL error: `column \"count\" row 0: SqlType::UInt32 cannot be cast to Array.`"
+            ),
+        }
+    }
+
     #[test]
     fn test_read_empty_block() {
         let source = [1, 0, 2, 255, 255, 255, 255, 0, 0, 0];
@@ -1,7 +1,7 @@
 use std::{marker, sync::Arc};
 
 use crate::{
-    errors::Result,
+    errors::{Error, FromSqlError, Result},
     types::{Block, block::ColumnIdx, ColumnType, Column, FromSql, SqlType},
 };
 
@@ -22,6 +22,26 @@ impl<'a, K: ColumnType> Row<'a, K> {
         self.block_ref.get(self.row, col)
     }
 
+    /// Get the elements of an `Array` cell of the row, failing up front if the
+    /// column isn't an array.
+    pub fn get_array<T, I>(&'a self, col: I) -> Result<Vec<T>>
+    where
+        Vec<T>: FromSql<'a>,
+        I: ColumnIdx + Copy,
+    {
+        match self.sql_type(col)? {
+            SqlType::Array(_) => self.get::<Vec<T>, _>(col),
+            sql_type => Err(Error::FromSql(FromSqlError::InColumn {
+                column: self.name(col)?.to_string(),
+                index: self.row,
+                source: Box::new(FromSqlError::InvalidType {
+                    src: sql_type.to_string(),
+                    dst: "Array".into(),
+                }),
+            })),
+        }
+    }
+
     /// Return the number of cells in the current row.
     pub fn len(&self) -> usize {
         self.block_ref.column_count()
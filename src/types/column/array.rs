@@ -94,6 +94,10 @@ impl ColumnData for ArrayColumnData {
         ValueRef::Array(sql_type.into(), Arc::new(vs))
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.memory_size() + self.offsets.as_ref().len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone(),
@@ -122,6 +126,32 @@ impl ColumnData for ArrayColumnData {
         }
         None
     }
+
+    fn validate(&self) -> Result<()> {
+        let mut prev = 0_u64;
+        for i in 0..self.offsets.len() {
+            let offset = self.offsets.at(i);
+            if offset < prev {
+                let message = format!(
+                    "Array offsets aren't monotonic at row {} ({} < {}).",
+                    i, offset, prev
+                );
+                return Err(message.into());
+            }
+            prev = offset;
+        }
+
+        if prev as usize != self.inner.len() {
+            let message = format!(
+                "Array offsets end at {}, but inner column has {} values.",
+                prev,
+                self.inner.len()
+            );
+            return Err(message.into());
+        }
+
+        self.inner.validate()
+    }
 }
 
 #[cfg(test)]
@@ -129,7 +159,7 @@ mod test {
     use std::io::Cursor;
 
     use super::*;
-    use crate::{Block, types::Simple};
+    use crate::{Block, types::{column::{Column, ColumnFrom}, Simple}};
 
     #[test]
     fn test_write_and_read() {
@@ -146,4 +176,42 @@ mod test {
 
         assert_eq!(block, rblock);
     }
+
+    #[test]
+    fn test_validate() {
+        let inner = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3, 4]);
+
+        let mut offsets = List::with_capacity(2);
+        offsets.push(1);
+        offsets.push(4);
+        let column = ArrayColumnData {
+            inner: inner.clone(),
+            offsets,
+        };
+        assert!(column.validate().is_ok());
+
+        let mut offsets = List::with_capacity(2);
+        offsets.push(3);
+        offsets.push(1);
+        let column = ArrayColumnData { inner, offsets };
+        assert!(column.validate().is_err());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_read_validates() {
+        let inner = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        let mut offsets = List::with_capacity(2);
+        offsets.push(3);
+        offsets.push(1);
+        let column = ArrayColumnData { inner, offsets };
+
+        let mut encoder = Encoder::new();
+        encoder.string("vals");
+        encoder.string("Array(UInt32)");
+        column.save(&mut encoder, 0, column.len());
+
+        let mut reader = Cursor::new(encoder.get_buffer_ref());
+        assert!(Column::<Simple>::read(&mut reader, 2, Tz::Zulu).is_err());
+    }
 }
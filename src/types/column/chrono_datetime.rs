@@ -1,6 +1,6 @@
 use chrono::prelude::*;
 use chrono_tz::Tz;
-use std::{ptr, slice, sync::Arc};
+use std::{mem, ptr, slice, sync::Arc};
 
 use crate::{
     binary::Encoder,
@@ -98,6 +98,10 @@ impl ColumnData for ChronoDateTimeColumnData {
         ValueRef::DateTime(v.timestamp() as u32, v.timezone())
     }
 
+    fn memory_size(&self) -> usize {
+        self.data.len() * mem::size_of::<DateTime<Tz>>()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             data: self.data.clone(),
@@ -189,6 +193,10 @@ impl ColumnData for ChronoDateTimeAdapter {
         unimplemented!()
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -2,8 +2,9 @@ use std::{cmp, ops};
 
 use crate::{
     binary::Encoder,
+    errors::Result,
     types::{
-        column::column_data::{ArcColumnData, BoxColumnData},
+        column::column_data::{copy_rows, ArcColumnData, BoxColumnData},
         SqlType, Value, ValueRef,
     },
 };
@@ -50,7 +51,20 @@ impl ColumnData for ChunkColumnData {
         self.data.at(index + self.range.start)
     }
 
+    fn memory_size(&self) -> usize {
+        self.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
+
+    fn validate(&self) -> Result<()> {
+        self.data.validate()
+    }
+
+    fn rechunk(&self) -> BoxColumnData {
+        let indices: Vec<usize> = (0..self.len()).collect();
+        copy_rows(self, &indices).expect("column of a loaded type can be rebuilt")
+    }
 }
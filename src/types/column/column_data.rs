@@ -1,15 +1,37 @@
-use std::{convert, sync::Arc};
+use std::{
+    collections::HashMap,
+    convert::{self, TryFrom},
+    sync::Arc,
+};
+
+use chrono_tz::Tz;
 
 use crate::{
     binary::Encoder,
     errors::{Error, FromSqlError, Result},
-    types::{SqlType, Value, ValueRef},
+    types::{
+        block::extract_timezone,
+        column::{BoxColumnWrapper, Either},
+        SqlType, Value, ValueRef,
+    },
 };
 
 pub(crate) type ArcColumnData = Arc<dyn ColumnData + Send + Sync>;
 
 pub(crate) type BoxColumnData = Box<dyn ColumnData + Send + Sync>;
 
+/// Summary of a numeric column, see `ColumnData::numeric_stats`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericStats {
+    /// Number of non-NULL values.
+    pub count: usize,
+    pub nulls: usize,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub sum: f64,
+    pub mean: Option<f64>,
+}
+
 pub trait ColumnData {
     fn sql_type(&self) -> SqlType;
     fn save(&self, encoder: &mut Encoder, start: usize, end: usize);
@@ -17,6 +39,23 @@ pub trait ColumnData {
     fn push(&mut self, value: Value);
     fn at(&self, index: usize) -> ValueRef;
 
+    /// Like `at`, but fails with `FromSqlError::IndexOutOfBounds` instead of
+    /// panicking when `index` is past the end of the column.
+    fn try_at(&self, index: usize) -> Result<ValueRef<'_>> {
+        let len = self.len();
+        if index >= len {
+            return Err(Error::FromSql(FromSqlError::IndexOutOfBounds {
+                index,
+                len,
+            }));
+        }
+        Ok(self.at(index))
+    }
+
+    /// Approximate number of bytes held by the column's data, e.g. for budgeting
+    /// the memory of in-flight blocks. Views count the whole column they share.
+    fn memory_size(&self) -> usize;
+
     fn clone_instance(&self) -> BoxColumnData;
 
     unsafe fn get_internal(&self, _pointers: &[*mut *const u8], _level: u8) -> Result<()> {
@@ -26,14 +65,780 @@ pub trait ColumnData {
     fn cast_to(&self, _this: &ArcColumnData, _target: &SqlType) -> Option<ArcColumnData> {
         None
     }
+
+    /// Checks structural invariants of the column (e.g. after manual construction).
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Converts the column to `target` numeric type (optionally `Nullable`).
+    ///
+    /// Fails with `FromSqlError::InvalidType` up front, even for an empty column, unless
+    /// the column type `is_compatible_with` the target. Widenings always succeed. Narrowings (to a smaller integer, across signedness,
+    /// float to integer, `Float64` to `Float32`, `Nullable(T)` to `T`) are checked per
+    /// value and fail with `FromSqlError::OutOfRange` if a value can't be represented
+    /// exactly.
+    fn cast(&self, target: &SqlType) -> Result<BoxColumnData> {
+        if self.sql_type() == *target {
+            return Ok(self.rechunk());
+        }
+        if !self.sql_type().is_compatible_with(target) {
+            return Err(Error::FromSql(FromSqlError::InvalidType {
+                src: self.sql_type().to_string(),
+                dst: target.to_string(),
+            }));
+        }
+
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(target.clone(), Tz::Zulu, self.len())?;
+        for index in 0..self.len() {
+            column.push(cast_value(self.at(index), target)?);
+        }
+        Ok(column)
+    }
+
+    /// Replaces NULLs of a `Nullable(T)` column with `value`, returning a column of `T`.
+    ///
+    /// `value` must have type `T`; non-nullable columns are returned unchanged.
+    fn fill_null(&self, value: Value) -> Result<BoxColumnData> {
+        let inner = match self.sql_type() {
+            SqlType::Nullable(inner) => inner.clone(),
+            sql_type => sql_type,
+        };
+
+        let value_type = SqlType::from(value.clone());
+        if value_type != inner {
+            return Err(Error::FromSql(FromSqlError::InvalidType {
+                src: value_type.to_string(),
+                dst: inner.to_string(),
+            }));
+        }
+
+        if inner == self.sql_type() {
+            return Ok(self.rechunk());
+        }
+
+        let timezone = extract_timezone(&value);
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(inner, timezone, self.len())?;
+        for index in 0..self.len() {
+            match self.at(index) {
+                ValueRef::Nullable(Either::Right(v)) => column.push((*v).into()),
+                _ => column.push(value.clone()),
+            }
+        }
+        Ok(column)
+    }
+
+    /// Picks `n` rows by reservoir sampling; the same `seed` always yields the same rows.
+    ///
+    /// Sampled rows keep their original order. If `n` is at least the number of rows,
+    /// the whole column is returned.
+    fn sample(&self, n: usize, seed: u64) -> Result<BoxColumnData> {
+        if n >= self.len() {
+            return Ok(self.rechunk());
+        }
+
+        let mut state = seed;
+        let mut indices: Vec<usize> = (0..n).collect();
+        for index in n..self.len() {
+            let j = (splitmix64(&mut state) % (index as u64 + 1)) as usize;
+            if j < n {
+                indices[j] = index;
+            }
+        }
+        indices.sort_unstable();
+
+        copy_rows(self, &indices)
+    }
+
+    /// Maps each distinct value of the column to the indices of the rows holding it.
+    fn group_by(&self) -> Result<HashMap<Value, Vec<usize>>> {
+        let mut groups: HashMap<Value, Vec<usize>> = HashMap::new();
+        for index in 0..self.len() {
+            groups.entry(self.at(index).into()).or_default().push(index);
+        }
+        Ok(groups)
+    }
+
+    /// Converts every row to JSON with `ValueRef::to_json`.
+    #[cfg(feature = "serde_json")]
+    fn to_json_rows(&self) -> Result<Vec<serde_json::Value>> {
+        Ok((0..self.len())
+            .map(|index| self.at(index).to_json())
+            .collect())
+    }
+
+    /// Returns the indices of rows where `other` holds a different value.
+    ///
+    /// Both columns must have the same type and length.
+    fn diff(&self, other: &dyn ColumnData) -> Result<Vec<usize>> {
+        if self.sql_type() != other.sql_type() {
+            return Err(Error::FromSql(FromSqlError::InvalidType {
+                src: other.sql_type().to_string(),
+                dst: self.sql_type().to_string(),
+            }));
+        }
+
+        if self.len() != other.len() {
+            return Err(format!(
+                "Can't diff columns of different lengths ({} and {}).",
+                self.len(),
+                other.len()
+            )
+            .into());
+        }
+
+        Ok((0..self.len())
+            .filter(|&index| self.at(index) != other.at(index))
+            .collect())
+    }
+
+    /// Computes count, NULL count, min, max, sum and mean of a numeric (or
+    /// `Nullable` numeric) column, with values coerced by `ValueRef::as_f64`.
+    fn numeric_stats(&self) -> Result<NumericStats> {
+        let sql_type = self.sql_type();
+        let inner = match &sql_type {
+            SqlType::Nullable(inner) => *inner,
+            sql_type => sql_type,
+        };
+        match inner {
+            SqlType::UInt8
+            | SqlType::UInt16
+            | SqlType::UInt32
+            | SqlType::UInt64
+            | SqlType::UInt128
+            | SqlType::Int8
+            | SqlType::Int16
+            | SqlType::Int32
+            | SqlType::Int64
+            | SqlType::Int128
+            | SqlType::Float32
+            | SqlType::Float64
+            | SqlType::Decimal(..) => {}
+            _ => {
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: sql_type.to_string(),
+                    dst: "NumericStats".into(),
+                }))
+            }
+        }
+
+        let mut stats = NumericStats {
+            count: 0,
+            nulls: 0,
+            min: None,
+            max: None,
+            sum: 0.0,
+            mean: None,
+        };
+        for index in 0..self.len() {
+            let v = match self.at(index) {
+                ValueRef::Nullable(Either::Left(_)) => {
+                    stats.nulls += 1;
+                    continue;
+                }
+                ValueRef::Nullable(Either::Right(v)) => v.as_f64()?,
+                v => v.as_f64()?,
+            };
+            stats.count += 1;
+            stats.sum += v;
+            stats.min = Some(stats.min.map_or(v, |min| min.min(v)));
+            stats.max = Some(stats.max.map_or(v, |max| max.max(v)));
+        }
+        if stats.count > 0 {
+            stats.mean = Some(stats.sum / stats.count as f64);
+        }
+        Ok(stats)
+    }
+
+    /// Returns a `Float64` column where row `i` is the sum of rows `i + 1 - window ..= i`,
+    /// with values coerced by `ValueRef::as_f64`.
+    ///
+    /// The first `window - 1` rows sum only the rows available so far.
+    fn window_sum(&self, window: usize) -> Result<BoxColumnData> {
+        if window == 0 {
+            return Err("window_sum needs a window of at least one row".into());
+        }
+
+        let values = (0..self.len())
+            .map(|index| self.at(index).as_f64())
+            .collect::<Result<Vec<f64>>>()?;
+
+        let mut column = <dyn ColumnData>::from_type::<BoxColumnWrapper>(
+            SqlType::Float64,
+            Tz::Zulu,
+            values.len(),
+        )?;
+        for index in 0..values.len() {
+            let start = (index + 1).saturating_sub(window);
+            column.push(Value::Float64(values[start..=index].iter().sum()));
+        }
+        Ok(column)
+    }
+
+    /// Returns a copy of the column backed by a single contiguous buffer.
+    ///
+    /// Chunked and concatenated columns are materialized; other columns are cloned.
+    fn rechunk(&self) -> BoxColumnData {
+        self.clone_instance()
+    }
+}
+
+pub(crate) fn copy_rows<C>(column: &C, indices: &[usize]) -> Result<BoxColumnData>
+where
+    C: ColumnData + ?Sized,
+{
+    let timezone = match indices.first() {
+        Some(&index) => extract_timezone(&column.at(index).into()),
+        None => Tz::Zulu,
+    };
+    let mut copy = <dyn ColumnData>::from_type::<BoxColumnWrapper>(
+        column.sql_type(),
+        timezone,
+        indices.len(),
+    )?;
+    for &index in indices {
+        copy.push(column.at(index).into());
+    }
+    Ok(copy)
+}
+
+/// Converts equally long columns into row-major values, one `Vec` per row.
+pub(crate) fn transpose(columns: &[&dyn ColumnData]) -> Result<Vec<Vec<Value>>> {
+    let rows = columns.first().map_or(0, |column| column.len());
+    if let Some(column) = columns.iter().find(|column| column.len() != rows) {
+        let message = format!(
+            "Can't transpose columns of different lengths ({} and {}).",
+            rows,
+            column.len()
+        );
+        return Err(message.into());
+    }
+
+    Ok((0..rows)
+        .map(|index| {
+            columns
+                .iter()
+                .map(|column| column.at(index).into())
+                .collect()
+        })
+        .collect())
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+fn cast_value(value: ValueRef, target: &SqlType) -> Result<Value> {
+    match (value, target) {
+        (ValueRef::Nullable(Either::Left(_)), SqlType::Nullable(inner)) => {
+            Ok(Value::Nullable(Either::Left(inner)))
+        }
+        (ValueRef::Nullable(Either::Right(value)), SqlType::Nullable(inner)) => Ok(
+            Value::Nullable(Either::Right(Box::new(cast_value(*value, inner)?))),
+        ),
+        (ValueRef::Nullable(Either::Left(_)), _) => Err(Error::FromSql(FromSqlError::OutOfRange)),
+        (ValueRef::Nullable(Either::Right(value)), _) => cast_value(*value, target),
+        (value, SqlType::Nullable(inner)) => Ok(Value::Nullable(Either::Right(Box::new(
+            cast_value(value, inner)?,
+        )))),
+        (value, _) => {
+            let number = match value {
+                ValueRef::UInt8(v) => Number::Int(v.into()),
+                ValueRef::UInt16(v) => Number::Int(v.into()),
+                ValueRef::UInt32(v) => Number::Int(v.into()),
+                ValueRef::UInt64(v) => Number::Int(v.into()),
+                ValueRef::UInt128(v) => match i128::try_from(v) {
+                    Ok(v) => Number::Int(v),
+                    Err(_) => return Err(Error::FromSql(FromSqlError::OutOfRange)),
+                },
+                ValueRef::Int8(v) => Number::Int(v.into()),
+                ValueRef::Int16(v) => Number::Int(v.into()),
+                ValueRef::Int32(v) => Number::Int(v.into()),
+                ValueRef::Int64(v) => Number::Int(v.into()),
+                ValueRef::Int128(v) => Number::Int(v),
+                ValueRef::Float32(v) => Number::Float(v.into()),
+                ValueRef::Float64(v) => Number::Float(v),
+                _ => return Err(invalid_cast(value, target)),
+            };
+            cast_number(number, target).ok_or_else(|| match target {
+                SqlType::UInt8
+                | SqlType::UInt16
+                | SqlType::UInt32
+                | SqlType::UInt64
+                | SqlType::UInt128
+                | SqlType::Int8
+                | SqlType::Int16
+                | SqlType::Int32
+                | SqlType::Int64
+                | SqlType::Int128
+                | SqlType::Float32
+                | SqlType::Float64 => Error::FromSql(FromSqlError::OutOfRange),
+                _ => invalid_cast(value, target),
+            })
+        }
+    }
+}
+
+fn cast_number(number: Number, target: &SqlType) -> Option<Value> {
+    let int = match number {
+        Number::Int(v) => Some(v),
+        Number::Float(v) if v.is_finite() && v.fract() == 0.0 => Some(v as i128),
+        Number::Float(_) => None,
+    };
+
+    macro_rules! int_target {
+        ($t:ty, $variant:ident) => {
+            int.and_then(|v| <$t>::try_from(v).ok())
+                .map(Value::$variant)
+        };
+    }
+
+    match target {
+        SqlType::UInt8 => int_target!(u8, UInt8),
+        SqlType::UInt16 => int_target!(u16, UInt16),
+        SqlType::UInt32 => int_target!(u32, UInt32),
+        SqlType::UInt64 => int_target!(u64, UInt64),
+        SqlType::UInt128 => int_target!(u128, UInt128),
+        SqlType::Int8 => int_target!(i8, Int8),
+        SqlType::Int16 => int_target!(i16, Int16),
+        SqlType::Int32 => int_target!(i32, Int32),
+        SqlType::Int64 => int_target!(i64, Int64),
+        SqlType::Int128 => int_target!(i128, Int128),
+        SqlType::Float32 => match number {
+            Number::Int(v) if (v as f32) as i128 == v => Some(Value::Float32(v as f32)),
+            Number::Float(v) if v.is_nan() || f64::from(v as f32) == v => {
+                Some(Value::Float32(v as f32))
+            }
+            _ => None,
+        },
+        SqlType::Float64 => match number {
+            Number::Int(v) if (v as f64) as i128 == v => Some(Value::Float64(v as f64)),
+            Number::Float(v) => Some(Value::Float64(v)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn invalid_cast(value: ValueRef, target: &SqlType) -> Error {
+    Error::FromSql(FromSqlError::InvalidType {
+        src: SqlType::from(value).to_string(),
+        dst: target.to_string(),
+    })
 }
 
 pub(crate) trait ColumnDataExt {
     fn append<T: convert::Into<Value>>(&mut self, value: T);
+
+    /// Builds a column from `f` applied to each pair of rows of `self` and `other`.
+    ///
+    /// The result type is taken from the first produced value (or `self` if both are
+    /// empty) and every value must have that type.
+    fn zip_map<F>(&self, other: &dyn ColumnData, f: F) -> Result<BoxColumnData>
+    where
+        F: FnMut(ValueRef, ValueRef) -> Value;
+
+    /// Counts the rows for which `pred` holds.
+    fn count_matching<F>(&self, pred: F) -> usize
+    where
+        F: Fn(ValueRef) -> bool;
+
+    /// Returns a copy of the column with the rows for which `pred` holds replaced by
+    /// `with`, which must have the column type.
+    fn replace<F>(&self, pred: F, with: Value) -> Result<BoxColumnData>
+    where
+        F: Fn(ValueRef) -> bool;
 }
 
-impl<C: ColumnData> ColumnDataExt for C {
+impl<C: ColumnData + ?Sized> ColumnDataExt for C {
     fn append<T: convert::Into<Value>>(&mut self, value: T) {
         self.push(value.into());
     }
+
+    fn zip_map<F>(&self, other: &dyn ColumnData, mut f: F) -> Result<BoxColumnData>
+    where
+        F: FnMut(ValueRef, ValueRef) -> Value,
+    {
+        if self.len() != other.len() {
+            return Err(format!(
+                "Can't zip columns of different lengths ({} and {}).",
+                self.len(),
+                other.len()
+            )
+            .into());
+        }
+
+        let values: Vec<Value> = (0..self.len())
+            .map(|index| f(self.at(index), other.at(index)))
+            .collect();
+
+        let (sql_type, timezone) = match values.first() {
+            Some(value) => (SqlType::from(value.clone()), extract_timezone(value)),
+            None => (self.sql_type(), Tz::Zulu),
+        };
+        let mut column = <dyn ColumnData>::from_type::<BoxColumnWrapper>(
+            sql_type.clone(),
+            timezone,
+            values.len(),
+        )?;
+        for value in values {
+            let value_type = SqlType::from(value.clone());
+            if value_type != sql_type {
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: value_type.to_string(),
+                    dst: sql_type.to_string(),
+                }));
+            }
+            column.push(value);
+        }
+        Ok(column)
+    }
+
+    fn count_matching<F>(&self, pred: F) -> usize
+    where
+        F: Fn(ValueRef) -> bool,
+    {
+        (0..self.len())
+            .filter(|&index| pred(self.at(index)))
+            .count()
+    }
+
+    fn replace<F>(&self, pred: F, with: Value) -> Result<BoxColumnData>
+    where
+        F: Fn(ValueRef) -> bool,
+    {
+        let sql_type = self.sql_type();
+        let value_type = SqlType::from(with.clone());
+        if value_type != sql_type {
+            return Err(Error::FromSql(FromSqlError::InvalidType {
+                src: value_type.to_string(),
+                dst: sql_type.to_string(),
+            }));
+        }
+
+        let timezone = match self.len() {
+            0 => extract_timezone(&with),
+            _ => extract_timezone(&self.at(0).into()),
+        };
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(sql_type, timezone, self.len())?;
+        for index in 0..self.len() {
+            let value = self.at(index);
+            if pred(value.clone()) {
+                column.push(with.clone());
+            } else {
+                column.push(value.into());
+            }
+        }
+        Ok(column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::types::column::{
+        chunk::ChunkColumnData, concat::ConcatColumnData, ArcColumnWrapper, ColumnFrom,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_at() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![vec![1_u8, 2], vec![3]]);
+        assert_eq!(column.try_at(1).unwrap(), column.at(1));
+
+        match column.try_at(2) {
+            Err(Error::FromSql(FromSqlError::IndexOutOfBounds { index, len })) => {
+                assert_eq!((index, len), (2, 2))
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[test]
+    fn test_cast_widening() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1_u8, 200, 255]);
+        let widened = column.cast(&SqlType::UInt32).unwrap();
+
+        assert_eq!(widened.sql_type(), SqlType::UInt32);
+        assert_eq!(widened.len(), 3);
+        assert_eq!(widened.at(0), ValueRef::UInt32(1));
+        assert_eq!(widened.at(1), ValueRef::UInt32(200));
+        assert_eq!(widened.at(2), ValueRef::UInt32(255));
+    }
+
+    #[test]
+    fn test_cast_narrowing() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1_i32, 300]);
+
+        let err = column.cast(&SqlType::UInt8).err().unwrap();
+        assert!(matches!(err, Error::FromSql(FromSqlError::OutOfRange)));
+
+        let narrowed = column.cast(&SqlType::Int16).unwrap();
+        assert_eq!(narrowed.at(1), ValueRef::Int16(300));
+    }
+
+    #[test]
+    fn test_cast_incompatible() {
+        let column = Vec::column_from::<ArcColumnWrapper>(Vec::<String>::new());
+        let err = column.cast(&SqlType::UInt32).err().unwrap();
+        assert!(matches!(err, Error::FromSql(FromSqlError::InvalidType { .. })));
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u8), None]);
+        let casted = column
+            .cast(&SqlType::Nullable(SqlType::Int64.into()))
+            .unwrap();
+        assert_eq!(casted.len(), 2);
+    }
+
+    #[test]
+    fn test_fill_null() {
+        let column =
+            Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), None, Some(3_u32), None]);
+        let filled = column.fill_null(Value::UInt32(0)).unwrap();
+
+        assert_eq!(filled.sql_type(), SqlType::UInt32);
+        let values: Vec<_> = (0..filled.len()).map(|i| filled.at(i)).collect();
+        assert_eq!(
+            values,
+            vec![
+                ValueRef::UInt32(1),
+                ValueRef::UInt32(0),
+                ValueRef::UInt32(3),
+                ValueRef::UInt32(0)
+            ]
+        );
+
+        assert!(column
+            .fill_null(Value::String(Arc::new(b"0".to_vec())))
+            .is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        let column = Vec::column_from::<ArcColumnWrapper>((0..100_u32).collect::<Vec<_>>());
+        let rows = |c: &BoxColumnData| {
+            (0..c.len())
+                .map(|i| Value::from(c.at(i)))
+                .collect::<Vec<_>>()
+        };
+
+        let first = column.sample(10, 42).unwrap();
+        let second = column.sample(10, 42).unwrap();
+        assert_eq!(first.len(), 10);
+        assert_eq!(rows(&first), rows(&second));
+
+        let other = column.sample(10, 7).unwrap();
+        assert_ne!(rows(&first), rows(&other));
+
+        assert_eq!(column.sample(200, 42).unwrap().len(), 100);
+    }
+
+    #[test]
+    fn test_group_by() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1_u8, 2, 1, 3, 2, 1]);
+        let groups = column.group_by().unwrap();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&Value::UInt8(1)], vec![0, 2, 5]);
+        assert_eq!(groups[&Value::UInt8(2)], vec![1, 4]);
+        assert_eq!(groups[&Value::UInt8(3)], vec![3]);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_to_json_rows() {
+        use serde_json::json;
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), None]);
+        assert_eq!(column.to_json_rows().unwrap(), vec![json!(1), json!(null)]);
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![vec!["a".to_string()], vec![]]);
+        assert_eq!(
+            column.to_json_rows().unwrap(),
+            vec![json!(["a"]), json!([])]
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), None, Some(3)]);
+        let after = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), Some(2), Some(3)]);
+        assert_eq!(before.diff(after.as_ref()).unwrap(), vec![1]);
+        assert_eq!(before.diff(before.as_ref()).unwrap(), Vec::<usize>::new());
+
+        let shorter = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32)]);
+        assert!(before.diff(shorter.as_ref()).is_err());
+
+        let other_type = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        assert!(before.diff(other_type.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_numeric_stats() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1.5_f64, -2.0, 4.5]);
+        assert_eq!(
+            column.numeric_stats().unwrap(),
+            NumericStats {
+                count: 3,
+                nulls: 0,
+                min: Some(-2.0),
+                max: Some(4.5),
+                sum: 4.0,
+                mean: Some(4.0 / 3.0),
+            }
+        );
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![Some(2_u8), None, Some(4)]);
+        let stats = column.numeric_stats().unwrap();
+        assert_eq!((stats.count, stats.nulls), (2, 1));
+        assert_eq!(stats.mean, Some(3.0));
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec!["a".to_string()]);
+        assert!(column.numeric_stats().is_err());
+    }
+
+    #[test]
+    fn test_window_sum() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1.0_f64, 2.0, 3.0, 4.0, 5.0]);
+        let sums = column.window_sum(3).unwrap();
+
+        assert_eq!(sums.sql_type(), SqlType::Float64);
+        let values: Vec<_> = (0..sums.len()).map(|i| sums.at(i)).collect();
+        assert_eq!(
+            values,
+            vec![
+                ValueRef::Float64(1.0),
+                ValueRef::Float64(3.0),
+                ValueRef::Float64(6.0),
+                ValueRef::Float64(9.0),
+                ValueRef::Float64(12.0)
+            ]
+        );
+
+        assert!(column.window_sum(0).is_err());
+        let strings = Vec::column_from::<ArcColumnWrapper>(vec!["a".to_string()]);
+        assert!(strings.window_sum(3).is_err());
+    }
+
+    #[test]
+    fn test_memory_size() {
+        let numbers = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        assert_eq!(numbers.memory_size(), 3 * 4);
+
+        let nullable = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), None, Some(3)]);
+        assert_eq!(nullable.memory_size(), 3 * 4 + 3);
+
+        let arrays = Vec::column_from::<ArcColumnWrapper>(vec![vec![1_u16, 2], vec![3]]);
+        assert_eq!(arrays.memory_size(), 3 * 2 + 2 * 8);
+    }
+
+    #[test]
+    fn test_replace() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![5_i32, -1, 7, -1]);
+        let cleaned = column
+            .replace(|v| v == ValueRef::Int32(-1), Value::Int32(0))
+            .unwrap();
+
+        assert_eq!(cleaned.sql_type(), SqlType::Int32);
+        let values: Vec<_> = (0..cleaned.len()).map(|i| cleaned.at(i)).collect();
+        assert_eq!(
+            values,
+            vec![
+                ValueRef::Int32(5),
+                ValueRef::Int32(0),
+                ValueRef::Int32(7),
+                ValueRef::Int32(0)
+            ]
+        );
+
+        assert!(column
+            .replace(|v| v == ValueRef::Int32(-1), Value::Int64(0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let numerator = Vec::column_from::<ArcColumnWrapper>(vec![1.0_f64, 3.0, 5.0]);
+        let denominator = Vec::column_from::<ArcColumnWrapper>(vec![2.0_f64, 4.0, 0.5]);
+
+        let ratio = numerator
+            .zip_map(denominator.as_ref(), |a, b| {
+                Value::Float64(f64::from(a) / f64::from(b))
+            })
+            .unwrap();
+        assert_eq!(ratio.sql_type(), SqlType::Float64);
+        assert_eq!(ratio.at(0), ValueRef::Float64(0.5));
+        assert_eq!(ratio.at(1), ValueRef::Float64(0.75));
+        assert_eq!(ratio.at(2), ValueRef::Float64(10.0));
+
+        let shorter = Vec::column_from::<ArcColumnWrapper>(vec![1.0_f64]);
+        assert!(numerator
+            .zip_map(shorter.as_ref(), |a, _| a.into())
+            .is_err());
+    }
+
+    #[test]
+    fn test_rechunk() {
+        let first = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        let second = Vec::column_from::<ArcColumnWrapper>(vec![4_u32, 5]);
+        let concat: ArcColumnData = Arc::new(ConcatColumnData::concat(vec![first, second]));
+        let chunk = ChunkColumnData::new(concat.clone(), 1..4);
+
+        let rechunked = concat.rechunk();
+        assert_eq!(rechunked.sql_type(), SqlType::UInt32);
+        assert_eq!(rechunked.len(), concat.len());
+        for i in 0..concat.len() {
+            assert_eq!(rechunked.at(i), concat.at(i));
+        }
+
+        let rechunked = chunk.rechunk();
+        assert_eq!(rechunked.len(), 3);
+        for i in 0..chunk.len() {
+            assert_eq!(rechunked.at(i), chunk.at(i));
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let ids = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2]);
+        let names = Vec::column_from::<ArcColumnWrapper>(vec!["a", "b"]);
+        let scores = Vec::column_from::<ArcColumnWrapper>(vec![0.5_f64, 1.5]);
+
+        let rows = transpose(&[ids.as_ref(), names.as_ref(), scores.as_ref()]).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![Value::UInt32(1), Value::from("a"), Value::Float64(0.5)],
+                vec![Value::UInt32(2), Value::from("b"), Value::Float64(1.5)],
+            ]
+        );
+
+        assert!(transpose(&[]).unwrap().is_empty());
+
+        let short = Vec::column_from::<ArcColumnWrapper>(vec![1_u8]);
+        assert!(transpose(&[ids.as_ref(), short.as_ref()]).is_err());
+    }
+
+    #[test]
+    fn test_count_matching() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![50_u32, 150, 100, 300]);
+        assert_eq!(column.count_matching(|v| u32::from(v) > 100), 2);
+        assert_eq!(column.count_matching(|_| false), 0);
+
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![Some(1_u32), None, None]);
+        let nulls = column.count_matching(|v| matches!(v, ValueRef::Nullable(Either::Left(_))));
+        assert_eq!(nulls, 2);
+    }
 }
@@ -1,4 +1,4 @@
-use std::iter;
+use std::{iter, mem};
 
 use crate::{
     binary::Encoder,
@@ -6,7 +6,7 @@ use crate::{
     types::{SqlType, Value, ValueRef},
 };
 
-use super::column_data::{ArcColumnData, BoxColumnData, ColumnData};
+use super::column_data::{copy_rows, ArcColumnData, BoxColumnData, ColumnData};
 
 pub struct ConcatColumnData {
     data: Vec<ArcColumnData>,
@@ -62,6 +62,11 @@ impl ColumnData for ConcatColumnData {
         chunk.at(index - self.index[chunk_index])
     }
 
+    fn memory_size(&self) -> usize {
+        let data_size: usize = self.data.iter().map(|column| column.memory_size()).sum();
+        data_size + self.index.len() * mem::size_of::<usize>()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -74,6 +79,18 @@ impl ColumnData for ConcatColumnData {
             Err(Error::FromSql(FromSqlError::UnsupportedOperation))
         }
     }
+
+    fn validate(&self) -> Result<()> {
+        for chunk in &self.data {
+            chunk.validate()?;
+        }
+        Ok(())
+    }
+
+    fn rechunk(&self) -> BoxColumnData {
+        let indices: Vec<usize> = (0..self.len()).collect();
+        copy_rows(self, &indices).expect("column of a loaded type can be rebuilt")
+    }
 }
 
 fn build_index<'a, I>(sizes: I) -> Vec<usize>
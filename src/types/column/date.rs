@@ -189,6 +189,10 @@ where
         self.data.at(index).to_date(self.tz)
     }
 
+    fn memory_size(&self) -> usize {
+        self.data.as_ref().len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             data: self.data.clone(),
@@ -69,6 +69,10 @@ impl ColumnData for DateTime64ColumnData {
         ValueRef::DateTime64(value, &self.params)
     }
 
+    fn memory_size(&self) -> usize {
+        self.data.as_ref().len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             data: self.data.clone(),
@@ -178,6 +178,10 @@ impl ColumnData for DecimalColumnData {
         })
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone_instance(),
@@ -238,6 +242,10 @@ impl<K: ColumnType> ColumnData for DecimalAdapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -298,6 +306,10 @@ impl<K: ColumnType> ColumnData for NullableDecimalAdapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
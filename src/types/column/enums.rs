@@ -72,6 +72,10 @@ impl ColumnData for Enum16ColumnData {
         ValueRef::Enum16(self.enum_values.clone(), Enum16(enum_value))
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone_instance(),
@@ -111,6 +115,10 @@ impl<K: ColumnType> ColumnData for Enum16Adapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -160,6 +168,10 @@ impl<K: ColumnType> ColumnData for NullableEnum16Adapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -267,6 +279,10 @@ impl ColumnData for Enum8ColumnData {
         ValueRef::Enum8(self.enum_values.clone(), Enum8(enum_value))
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone_instance(),
@@ -306,6 +322,10 @@ impl<K: ColumnType> ColumnData for Enum8Adapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -356,6 +376,10 @@ impl<K: ColumnType> ColumnData for NullableEnum8Adapter<K> {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
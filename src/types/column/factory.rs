@@ -23,6 +23,8 @@ use crate::{
             fixed_string::FixedStringColumnData,
             ip::{IpColumnData, Ipv4, Ipv6, Uuid},
             list::List,
+            low_cardinality,
+            map::MapColumnData,
             nullable::NullableColumnData,
             numeric::VectorColumnData,
             string::StringColumnData,
@@ -62,10 +64,12 @@ impl dyn ColumnData {
             "UInt16" => W::wrap(VectorColumnData::<u16>::load(reader, size)?),
             "UInt32" => W::wrap(VectorColumnData::<u32>::load(reader, size)?),
             "UInt64" => W::wrap(VectorColumnData::<u64>::load(reader, size)?),
+            "UInt128" => W::wrap(VectorColumnData::<u128>::load(reader, size)?),
             "Int8" | "TinyInt" => W::wrap(VectorColumnData::<i8>::load(reader, size)?),
             "Int16" | "SmallInt" => W::wrap(VectorColumnData::<i16>::load(reader, size)?),
             "Int32" | "Int" | "Integer" => W::wrap(VectorColumnData::<i32>::load(reader, size)?),
             "Int64" | "BigInt" => W::wrap(VectorColumnData::<i64>::load(reader, size)?),
+            "Int128" => W::wrap(VectorColumnData::<i128>::load(reader, size)?),
             "Float32" | "Float" => W::wrap(VectorColumnData::<f32>::load(reader, size)?),
             "Float64" | "Double" => W::wrap(VectorColumnData::<f64>::load(reader, size)?),
             "String" | "Char" | "Varchar" | "Text" | "TinyText" | "MediumText" | "LongText" | "Blob" | "TinyBlob" | "MediumBlob" | "LongBlob" => W::wrap(StringColumnData::load(reader, size)?),
@@ -81,6 +85,18 @@ impl dyn ColumnData {
                     W::wrap(FixedStringColumnData::load(reader, size, str_len)?)
                 } else if let Some(inner_type) = parse_array_type(type_name) {
                     W::wrap(ArrayColumnData::load(reader, inner_type, size, tz)?)
+                } else if let Some(inner_type) = parse_low_cardinality_type(type_name) {
+                    let nullable_inner = parse_nullable_type(inner_type);
+                    let dictionary_type = nullable_inner.unwrap_or(inner_type);
+                    W::wrap_arc(low_cardinality::load(
+                        reader,
+                        dictionary_type,
+                        nullable_inner.is_some(),
+                        size,
+                        tz,
+                    )?)
+                } else if let Some((key_type, value_type)) = parse_map_type(type_name) {
+                    W::wrap(MapColumnData::load(reader, key_type, value_type, size, tz)?)
                 } else if let Some((precision, scale, nobits)) = parse_decimal(type_name) {
                     W::wrap(DecimalColumnData::load(
                         reader, precision, scale, nobits, size, tz,
@@ -110,10 +126,12 @@ impl dyn ColumnData {
             SqlType::UInt16 => W::wrap(VectorColumnData::<u16>::with_capacity(capacity)),
             SqlType::UInt32 => W::wrap(VectorColumnData::<u32>::with_capacity(capacity)),
             SqlType::UInt64 => W::wrap(VectorColumnData::<u64>::with_capacity(capacity)),
+            SqlType::UInt128 => W::wrap(VectorColumnData::<u128>::with_capacity(capacity)),
             SqlType::Int8 => W::wrap(VectorColumnData::<i8>::with_capacity(capacity)),
             SqlType::Int16 => W::wrap(VectorColumnData::<i16>::with_capacity(capacity)),
             SqlType::Int32 => W::wrap(VectorColumnData::<i32>::with_capacity(capacity)),
             SqlType::Int64 => W::wrap(VectorColumnData::<i64>::with_capacity(capacity)),
+            SqlType::Int128 => W::wrap(VectorColumnData::<i128>::with_capacity(capacity)),
             SqlType::String => W::wrap(StringColumnData::with_capacity(capacity)),
             SqlType::FixedString(len) => {
                 W::wrap(FixedStringColumnData::with_capacity(capacity, len))
@@ -132,12 +150,25 @@ impl dyn ColumnData {
             SqlType::DateTime(_) => W::wrap(DateColumnData::<u32>::with_capacity(capacity, timezone)),
             SqlType::Nullable(inner_type) => W::wrap(NullableColumnData {
                 inner: ColumnData::from_type::<ArcColumnWrapper>(inner_type.clone(), timezone, capacity)?,
-                nulls: Vec::new(),
+                nulls: Vec::with_capacity(capacity),
             }),
             SqlType::Array(inner_type) => W::wrap(ArrayColumnData {
                 inner: ColumnData::from_type::<ArcColumnWrapper>(inner_type.clone(), timezone, capacity)?,
                 offsets: List::with_capacity(capacity),
             }),
+            SqlType::Map(key_type, value_type) => W::wrap(MapColumnData {
+                keys: <dyn ColumnData>::from_type::<ArcColumnWrapper>(
+                    key_type.clone(),
+                    timezone,
+                    capacity,
+                )?,
+                values: <dyn ColumnData>::from_type::<ArcColumnWrapper>(
+                    value_type.clone(),
+                    timezone,
+                    capacity,
+                )?,
+                offsets: List::with_capacity(capacity),
+            }),
             SqlType::Decimal(precision, scale) => {
                 let nobits = NoBits::from_precision(precision).unwrap();
 
@@ -210,6 +241,36 @@ fn parse_array_type(source: &str) -> Option<&str> {
     Some(inner_type)
 }
 
+fn parse_low_cardinality_type(source: &str) -> Option<&str> {
+    if !source.starts_with("LowCardinality(") || !source.ends_with(')') {
+        return None;
+    }
+
+    Some(&source[15..source.len() - 1])
+}
+
+fn parse_map_type(source: &str) -> Option<(&str, &str)> {
+    if !source.starts_with("Map(") || !source.ends_with(')') {
+        return None;
+    }
+
+    let inner = &source[4..source.len() - 1];
+    let mut depth = 0_usize;
+    let mut quoted = false;
+    for (idx, byte) in inner.bytes().enumerate() {
+        match byte {
+            b'\'' => quoted = !quoted,
+            b'(' if !quoted => depth += 1,
+            b')' if !quoted => depth = depth.checked_sub(1)?,
+            b',' if !quoted && depth == 0 => {
+                return Some((inner[..idx].trim(), inner[idx + 1..].trim()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 fn parse_decimal(source: &str) -> Option<(u8, u8, NoBits)> {
     if source.len() < 12 {
         return None;
@@ -428,6 +489,24 @@ mod test {
         assert_eq!(parse_array_type("Array(UInt8)"), Some("UInt8"));
     }
 
+    #[test]
+    fn test_parse_map_type() {
+        assert_eq!(
+            parse_map_type("Map(String, UInt32)"),
+            Some(("String", "UInt32"))
+        );
+        assert_eq!(
+            parse_map_type("Map(String, Map(Decimal(9, 2), Array(Int8)))"),
+            Some(("String", "Map(Decimal(9, 2), Array(Int8))"))
+        );
+        assert_eq!(
+            parse_map_type("Map(Enum8('a,b' = 1), UInt8)"),
+            Some(("Enum8('a,b' = 1)", "UInt8"))
+        );
+        assert_eq!(parse_map_type("Map(String)"), None);
+        assert_eq!(parse_map_type("Array(UInt8)"), None);
+    }
+
     #[test]
     fn test_parse_nullable_type() {
         assert_eq!(parse_nullable_type("Nullable(Int8)"), Some("Int8"));
@@ -76,6 +76,10 @@ impl ColumnData for FixedStringColumnData {
         ValueRef::String(str_ref)
     }
 
+    fn memory_size(&self) -> usize {
+        self.buffer.len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             buffer: self.buffer.clone(),
@@ -133,6 +137,10 @@ impl<K: ColumnType> ColumnData for FixedStringAdapter<K> {
         self.column.at(index)
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
@@ -180,6 +188,10 @@ impl<K: ColumnType> ColumnData for NullableFixedStringAdapter<K> {
         self.column.at(index)
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
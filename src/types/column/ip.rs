@@ -296,6 +296,10 @@ impl<V: IpVersion> ColumnData for IpColumnData<V> {
         V::get(&self.inner, index)
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone(),
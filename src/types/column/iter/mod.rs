@@ -51,11 +51,13 @@ simple_num_iterable! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     i8: Int8,
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
 
     f32: Float32,
     f64: Float64
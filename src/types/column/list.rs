@@ -31,6 +31,11 @@ where
         List { data: Vec::new() }
     }
 
+    #[cfg(test)]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
     pub fn with_capacity(capacity: usize) -> List<T> {
         Self {
             data: Vec::with_capacity(capacity),
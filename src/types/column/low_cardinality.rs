@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+
+use crate::{
+    binary::ReadEx,
+    errors::Result,
+    types::{
+        column::{
+            column_data::{copy_rows, ArcColumnData},
+            ArcColumnWrapper, ColumnData,
+        },
+        StatBuffer, Unmarshal,
+    },
+};
+
+const SHARED_DICTIONARIES_WITH_ADDITIONAL_KEYS: u64 = 1;
+
+const INDEX_TYPE_MASK: u64 = 0xff;
+const NEED_GLOBAL_DICTIONARY_BIT: u64 = 1 << 8;
+const HAS_ADDITIONAL_KEYS_BIT: u64 = 1 << 9;
+
+/// Loads a `LowCardinality(T)` column and decodes it into a plain `T` column, so
+/// its values read exactly like those of `T`.
+///
+/// `type_name` is the dictionary type, i.e. `T` without `Nullable`. For
+/// `LowCardinality(Nullable(T))` pass `nullable`: dictionary key `0` then stands for
+/// `NULL` and the result is a `Nullable(T)` column.
+pub(crate) fn load<R: ReadEx>(
+    reader: &mut R,
+    type_name: &str,
+    nullable: bool,
+    size: usize,
+    tz: Tz,
+) -> Result<ArcColumnData> {
+    if size == 0 {
+        let dictionary =
+            <dyn ColumnData>::load_data::<ArcColumnWrapper, _>(reader, type_name, 0, tz)?;
+        return materialize(dictionary, &[], nullable);
+    }
+
+    let version: u64 = reader.read_scalar()?;
+    if version != SHARED_DICTIONARIES_WITH_ADDITIONAL_KEYS {
+        let message = format!(
+            "Unsupported LowCardinality serialization version {}.",
+            version
+        );
+        return Err(message.into());
+    }
+
+    let flags: u64 = reader.read_scalar()?;
+    if flags & NEED_GLOBAL_DICTIONARY_BIT != 0 {
+        return Err("LowCardinality columns with a global dictionary are not supported.".into());
+    }
+    if flags & HAS_ADDITIONAL_KEYS_BIT == 0 {
+        return Err("LowCardinality column has no dictionary keys of its own.".into());
+    }
+
+    let keys: u64 = reader.read_scalar()?;
+    let dictionary =
+        <dyn ColumnData>::load_data::<ArcColumnWrapper, _>(reader, type_name, keys as usize, tz)?;
+
+    let rows: u64 = reader.read_scalar()?;
+    if rows as usize != size {
+        let message = format!(
+            "LowCardinality column has {} rows, expected {}.",
+            rows, size
+        );
+        return Err(message.into());
+    }
+
+    let indices = match flags & INDEX_TYPE_MASK {
+        0 => read_indices::<u8, _>(reader, size)?,
+        1 => read_indices::<u16, _>(reader, size)?,
+        2 => read_indices::<u32, _>(reader, size)?,
+        3 => read_indices::<u64, _>(reader, size)?,
+        index_type => {
+            let message = format!("Unknown LowCardinality index type {}.", index_type);
+            return Err(message.into());
+        }
+    };
+
+    if let Some(index) = indices.iter().find(|&&index| index >= dictionary.len()) {
+        let message = format!(
+            "LowCardinality index {} is out of a dictionary of {} keys.",
+            index,
+            dictionary.len()
+        );
+        return Err(message.into());
+    }
+
+    materialize(dictionary, &indices, nullable)
+}
+
+fn read_indices<V, R>(reader: &mut R, size: usize) -> Result<Vec<usize>>
+where
+    V: Copy + Unmarshal<V> + StatBuffer + Into<u64>,
+    R: ReadEx,
+{
+    let mut indices = Vec::with_capacity(size);
+    for _ in 0..size {
+        let index: V = reader.read_scalar()?;
+        indices.push(index.into() as usize);
+    }
+    Ok(indices)
+}
+
+fn materialize(
+    dictionary: ArcColumnData,
+    indices: &[usize],
+    nullable: bool,
+) -> Result<ArcColumnData> {
+    let values: ArcColumnData = Arc::from(copy_rows(dictionary.as_ref(), indices)?);
+    if !nullable {
+        return Ok(values);
+    }
+
+    let mask = indices.iter().map(|&index| index == 0).collect();
+    Ok(Arc::from(values.into_nullable(mask)?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{
+        binary::Encoder,
+        types::{column::BoxColumnWrapper, FromSql, SqlType},
+    };
+
+    use super::*;
+
+    fn encode_colors() -> Vec<u8> {
+        encode_colors_with_flags(HAS_ADDITIONAL_KEYS_BIT | (1 << 10))
+    }
+
+    fn encode_colors_with_flags(flags: u64) -> Vec<u8> {
+        let mut encoder = Encoder::new();
+        encoder.write(SHARED_DICTIONARIES_WITH_ADDITIONAL_KEYS);
+        encoder.write(flags);
+        encoder.write(3_u64);
+        for key in &["", "red", "blue"] {
+            encoder.string(key);
+        }
+        encoder.write(4_u64);
+        for &index in &[1_u8, 2, 1, 0] {
+            encoder.write(index);
+        }
+        encoder.get_buffer()
+    }
+
+    #[test]
+    fn test_load_low_cardinality_string() {
+        let source = encode_colors();
+        let mut reader = Cursor::new(&source[..]);
+        let column = <dyn ColumnData>::load_data::<BoxColumnWrapper, _>(
+            &mut reader,
+            "LowCardinality(String)",
+            4,
+            Tz::Zulu,
+        )
+        .unwrap();
+
+        assert_eq!(column.sql_type(), SqlType::String);
+        let values: Vec<String> = (0..column.len())
+            .map(|i| String::from_sql(column.at(i)).unwrap())
+            .collect();
+        assert_eq!(values, vec!["red", "blue", "red", ""]);
+    }
+
+    #[test]
+    fn test_load_low_cardinality_nullable_string() {
+        let source = encode_colors();
+        let mut reader = Cursor::new(&source[..]);
+        let column = <dyn ColumnData>::load_data::<BoxColumnWrapper, _>(
+            &mut reader,
+            "LowCardinality(Nullable(String))",
+            4,
+            Tz::Zulu,
+        )
+        .unwrap();
+
+        assert_eq!(column.sql_type(), SqlType::Nullable(SqlType::String.into()));
+        let values: Vec<Option<String>> = (0..column.len())
+            .map(|i| Option::<String>::from_sql(column.at(i)).unwrap())
+            .collect();
+        let expected = vec![
+            Some("red".to_string()),
+            Some("blue".to_string()),
+            Some("red".to_string()),
+            None,
+        ];
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_load_low_cardinality_bad_index() {
+        let mut source = encode_colors();
+        *source.last_mut().unwrap() = 7;
+        let mut reader = Cursor::new(&source[..]);
+        assert!(load(&mut reader, "String", false, 4, Tz::Zulu).is_err());
+    }
+
+    #[test]
+    fn test_load_low_cardinality_unsupported_flags() {
+        let cases = [
+            (
+                NEED_GLOBAL_DICTIONARY_BIT | HAS_ADDITIONAL_KEYS_BIT,
+                "Other error: `LowCardinality columns with a global dictionary are not supported.`",
+            ),
+            (
+                0,
+                "Other error: `LowCardinality column has no dictionary keys of its own.`",
+            ),
+        ];
+        for (flags, expected) in cases.iter() {
+            let source = encode_colors_with_flags(*flags);
+            let mut reader = Cursor::new(&source[..]);
+            match load(&mut reader, "String", false, 4, Tz::Zulu) {
+                Ok(_) => panic!("should fail"),
+                Err(e) => assert_eq!(format!("{}", e), *expected),
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+
+use crate::{
+    binary::{Encoder, ReadEx},
+    errors::Result,
+    types::{
+        column::{
+            column_data::{ArcColumnData, BoxColumnData},
+            list::List,
+            ArcColumnWrapper, ColumnData,
+        },
+        SqlType, Value, ValueRef,
+    },
+};
+
+/// `Map(K, V)` is stored as `Array(Tuple(K, V))`: row offsets followed by
+/// a column of all keys and a column of all values.
+pub(crate) struct MapColumnData {
+    pub(crate) keys: ArcColumnData,
+    pub(crate) values: ArcColumnData,
+    pub(crate) offsets: List<u64>,
+}
+
+impl MapColumnData {
+    pub(crate) fn load<R: ReadEx>(
+        reader: &mut R,
+        key_type: &str,
+        value_type: &str,
+        rows: usize,
+        tz: Tz,
+    ) -> Result<Self> {
+        let mut offsets = List::with_capacity(rows);
+        offsets.resize(rows, 0_u64);
+        reader.read_bytes(offsets.as_mut())?;
+
+        let size = match rows {
+            0 => 0,
+            _ => offsets.at(rows - 1) as usize,
+        };
+        let keys = <dyn ColumnData>::load_data::<ArcColumnWrapper, _>(reader, key_type, size, tz)?;
+        let values =
+            <dyn ColumnData>::load_data::<ArcColumnWrapper, _>(reader, value_type, size, tz)?;
+
+        Ok(MapColumnData {
+            keys,
+            values,
+            offsets,
+        })
+    }
+}
+
+impl ColumnData for MapColumnData {
+    fn sql_type(&self) -> SqlType {
+        let key_type = self.keys.sql_type();
+        let value_type = self.values.sql_type();
+        SqlType::Map(key_type.into(), value_type.into())
+    }
+
+    fn save(&self, encoder: &mut Encoder, start: usize, end: usize) {
+        let mut offset = 0_u64;
+
+        for i in start..end {
+            offset = self.offsets.at(i);
+            encoder.write(offset);
+        }
+
+        self.keys.save(encoder, 0, offset as usize);
+        self.values.save(encoder, 0, offset as usize);
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn push(&mut self, value: Value) {
+        if let Value::Map(_, _, entries) = value {
+            let offsets_len = self.offsets.len();
+            let prev = if offsets_len == 0 {
+                0_usize
+            } else {
+                self.offsets.at(offsets_len - 1) as usize
+            };
+
+            let keys = Arc::get_mut(&mut self.keys).unwrap();
+            let values = Arc::get_mut(&mut self.values).unwrap();
+            self.offsets.push((prev + entries.len()) as u64);
+            for (k, v) in entries.iter() {
+                keys.push(k.clone());
+                values.push(v.clone());
+            }
+        } else {
+            panic!("value should be a map")
+        }
+    }
+
+    fn at(&self, index: usize) -> ValueRef<'_> {
+        let key_type = self.keys.sql_type();
+        let value_type = self.values.sql_type();
+
+        let start = if index > 0 {
+            self.offsets.at(index - 1) as usize
+        } else {
+            0_usize
+        };
+        let end = self.offsets.at(index) as usize;
+        let mut entries = Vec::with_capacity(end - start);
+        for i in start..end {
+            entries.push((self.keys.at(i), self.values.at(i)));
+        }
+        ValueRef::Map(key_type.into(), value_type.into(), Arc::new(entries))
+    }
+
+    fn memory_size(&self) -> usize {
+        self.keys.memory_size() + self.values.memory_size() + self.offsets.as_ref().len()
+    }
+
+    fn clone_instance(&self) -> BoxColumnData {
+        Box::new(Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            offsets: self.offsets.clone(),
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut prev = 0_u64;
+        for i in 0..self.offsets.len() {
+            let offset = self.offsets.at(i);
+            if offset < prev {
+                let message = format!(
+                    "Map offsets aren't monotonic at row {} ({} < {}).",
+                    i, offset, prev
+                );
+                return Err(message.into());
+            }
+            prev = offset;
+        }
+
+        if prev as usize != self.keys.len() || prev as usize != self.values.len() {
+            let message = format!(
+                "Map offsets end at {}, but there are {} keys and {} values.",
+                prev,
+                self.keys.len(),
+                self.values.len()
+            );
+            return Err(message.into());
+        }
+
+        self.keys.validate()?;
+        self.values.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::types::column::BoxColumnWrapper;
+
+    #[test]
+    fn test_write_and_read() {
+        let map_type = SqlType::Map(SqlType::String.into(), SqlType::UInt32.into());
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(map_type.clone(), Tz::Zulu, 2).unwrap();
+        column.push(Value::Map(
+            SqlType::String.into(),
+            SqlType::UInt32.into(),
+            Arc::new(vec![("a".into(), 1_u32.into()), ("b".into(), 2_u32.into())]),
+        ));
+        column.push(Value::Map(
+            SqlType::String.into(),
+            SqlType::UInt32.into(),
+            Arc::new(Vec::new()),
+        ));
+
+        let mut encoder = Encoder::new();
+        column.save(&mut encoder, 0, column.len());
+
+        let mut reader = Cursor::new(encoder.get_buffer_ref());
+        let loaded = <dyn ColumnData>::load_data::<BoxColumnWrapper, _>(
+            &mut reader,
+            "Map(String, UInt32)",
+            2,
+            Tz::Zulu,
+        )
+        .unwrap();
+
+        assert_eq!(loaded.sql_type(), map_type);
+        assert!(loaded.validate().is_ok());
+        assert_eq!(loaded.at(0), column.at(0));
+        assert_eq!(loaded.at(1), column.at(1));
+    }
+}
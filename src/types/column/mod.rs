@@ -28,7 +28,10 @@ use crate::{
 };
 
 use self::chunk::ChunkColumnData;
-pub(crate) use self::{column_data::ColumnData, string_pool::StringPool};
+pub(crate) use self::{
+    column_data::{transpose, ColumnData},
+    string_pool::StringPool,
+};
 pub use self::{concat::ConcatColumnData, numeric::VectorColumnData};
 
 mod array;
@@ -45,6 +48,8 @@ pub(crate) mod fixed_string;
 mod ip;
 pub(crate) mod iter;
 mod list;
+mod low_cardinality;
+mod map;
 mod nullable;
 mod numeric;
 mod string;
@@ -193,6 +198,9 @@ impl<K: ColumnType> Column<K> {
         let name = reader.read_string()?;
         let type_name = reader.read_string()?;
         let data = ColumnData::load_data::<ArcColumnWrapper, _>(reader, &type_name, size, tz)?;
+        if cfg!(debug_assertions) {
+            data.validate()?;
+        }
         let column = Self {
             name,
             data,
@@ -510,7 +518,7 @@ impl ColumnWrapper for BoxColumnWrapper {
         Box::new(column)
     }
 
-    fn wrap_arc(_: ArcColumnData) -> Self::Wrapper {
-        unimplemented!()
+    fn wrap_arc(data: ArcColumnData) -> Self::Wrapper {
+        data.clone_instance()
     }
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::{
     binary::{Encoder, ReadEx},
-    errors::Result,
+    errors::{Error, FromSqlError, Result},
     types::{
         column::{column_data::{BoxColumnData, ArcColumnData}, Either, ArcColumnWrapper, ColumnData},
         SqlType, Value, ValueRef,
@@ -30,6 +30,33 @@ impl NullableColumnData {
     }
 }
 
+impl dyn ColumnData + Send + Sync {
+    /// Wraps the column into `Nullable(T)`, positions set in `mask` become `NULL`.
+    pub(crate) fn into_nullable(self: Arc<Self>, mask: Vec<bool>) -> Result<BoxColumnData> {
+        let sql_type = self.sql_type();
+        if let SqlType::Nullable(_) | SqlType::Array(_) = sql_type {
+            return Err(Error::FromSql(FromSqlError::InvalidType {
+                src: sql_type.to_string(),
+                dst: format!("Nullable({})", sql_type).into(),
+            }));
+        }
+
+        if mask.len() != self.len() {
+            let message = format!(
+                "Null mask has {} elements, but column has {} rows.",
+                mask.len(),
+                self.len()
+            );
+            return Err(message.into());
+        }
+
+        Ok(Box::new(NullableColumnData {
+            inner: self,
+            nulls: mask.into_iter().map(|is_null| is_null as u8).collect(),
+        }))
+    }
+}
+
 impl ColumnData for NullableColumnData {
     fn sql_type(&self) -> SqlType {
         let inner_type = self.inner.sql_type();
@@ -78,6 +105,10 @@ impl ColumnData for NullableColumnData {
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.inner.memory_size() + self.nulls.len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             inner: self.inner.clone(),
@@ -106,4 +137,64 @@ impl ColumnData for NullableColumnData {
         }
         None
     }
+
+    fn validate(&self) -> Result<()> {
+        if self.nulls.len() != self.inner.len() {
+            let message = format!(
+                "Nullable column has {} null flags, but {} values.",
+                self.nulls.len(),
+                self.inner.len()
+            );
+            return Err(message.into());
+        }
+
+        if let Some(flag) = self.nulls.iter().find(|flag| **flag > 1) {
+            let message = format!("Nullable column has invalid null flag {}.", flag);
+            return Err(message.into());
+        }
+
+        self.inner.validate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::{column::ColumnFrom, FromSql};
+
+    #[test]
+    fn test_into_nullable() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        let nullable = column.into_nullable(vec![false, true, false]).unwrap();
+
+        assert_eq!(nullable.sql_type(), SqlType::Nullable(SqlType::UInt32.into()));
+
+        let actual: Vec<Option<u32>> = (0..nullable.len())
+            .map(|i| Option::<u32>::from_sql(nullable.at(i)).unwrap())
+            .collect();
+        assert_eq!(actual, vec![Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_validate() {
+        let inner = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+
+        let column = NullableColumnData {
+            inner: inner.clone(),
+            nulls: vec![0, 1, 0],
+        };
+        assert!(column.validate().is_ok());
+
+        let column = NullableColumnData {
+            inner,
+            nulls: vec![0, 1],
+        };
+        assert!(column.validate().is_err());
+    }
+
+    #[test]
+    fn test_into_nullable_bad_mask() {
+        let column = Vec::column_from::<ArcColumnWrapper>(vec![1_u32, 2, 3]);
+        assert!(column.into_nullable(vec![false, true]).is_err());
+    }
 }
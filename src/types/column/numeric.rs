@@ -203,11 +203,13 @@ where
             Value::UInt16(x) => ValueRef::UInt16(x),
             Value::UInt32(x) => ValueRef::UInt32(x),
             Value::UInt64(x) => ValueRef::UInt64(x),
+            Value::UInt128(x) => ValueRef::UInt128(x),
 
             Value::Int8(x) => ValueRef::Int8(x),
             Value::Int16(x) => ValueRef::Int16(x),
             Value::Int32(x) => ValueRef::Int32(x),
             Value::Int64(x) => ValueRef::Int64(x),
+            Value::Int128(x) => ValueRef::Int128(x),
 
             Value::Float32(x) => ValueRef::Float32(x),
             Value::Float64(x) => ValueRef::Float64(x),
@@ -216,6 +218,10 @@ where
         }
     }
 
+    fn memory_size(&self) -> usize {
+        self.data.as_ref().len()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             data: self.data.clone(),
@@ -235,3 +241,21 @@ pub(crate) fn save_data<T>(data: &[u8], encoder: &mut Encoder, start: usize, end
     let end_index = end * mem::size_of::<T>();
     encoder.write_bytes(&data[start_index..end_index]);
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_with_capacity() {
+        let mut column = VectorColumnData::<u32>::with_capacity(1_000);
+        let capacity = column.data.capacity();
+        assert!(capacity >= 1_000);
+
+        for i in 0..1_000 {
+            column.push(Value::UInt32(i));
+        }
+        assert_eq!(column.len(), 1_000);
+        assert_eq!(column.data.capacity(), capacity);
+    }
+}
@@ -187,6 +187,10 @@ impl ColumnData for StringColumnData {
         ValueRef::from(s)
     }
 
+    fn memory_size(&self) -> usize {
+        self.pool.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         Box::new(Self {
             pool: self.pool.clone(),
@@ -208,7 +212,7 @@ impl<K: ColumnType> ColumnData for StringAdapter<K> {
 
     fn save(&self, encoder: &mut Encoder, start: usize, end: usize) {
         for index in start..end {
-            let buf: Vec<u8> = Vec::from_sql(self.column.at(index)).unwrap();
+            let buf = Vec::<u8>::from_sql(self.column.at(index)).unwrap();
             encoder.byte_string(buf);
         }
     }
@@ -225,6 +229,10 @@ impl<K: ColumnType> ColumnData for StringAdapter<K> {
         self.column.at(index)
     }
 
+    fn memory_size(&self) -> usize {
+        self.column.data.memory_size()
+    }
+
     fn clone_instance(&self) -> BoxColumnData {
         unimplemented!()
     }
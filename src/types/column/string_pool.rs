@@ -1,4 +1,4 @@
-use std::{io::Write, slice};
+use std::{io::Write, mem, slice};
 
 const AVG_STR_SIZE: usize = 80;
 
@@ -128,6 +128,11 @@ impl StringPool {
         self.pointers.len()
     }
 
+    pub(crate) fn memory_size(&self) -> usize {
+        let chunks: usize = self.chunks.iter().map(Vec::len).sum();
+        chunks + self.pointers.len() * mem::size_of::<StringPtr>()
+    }
+
     pub(crate) fn strings(&self) -> StringIter {
         StringIter {
             pool: self,
@@ -1,4 +1,8 @@
-use std::{fmt, cmp::Ordering};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 static FACTORS10: &[i64] = &[
     1,
@@ -36,6 +40,17 @@ pub(crate) enum NoBits {
     N64,
 }
 
+/// How `Decimal::to_f64_rounded` treats the dropped digits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, ties to the even neighbour (banker's rounding).
+    HalfEven,
+    /// Round to the nearest value, ties away from zero.
+    HalfUp,
+    /// Drop the extra digits (round towards zero).
+    Truncate,
+}
+
 /// Provides arbitrary-precision floating point decimal.
 #[derive(Clone)]
 pub struct Decimal {
@@ -125,6 +140,20 @@ impl PartialEq for Decimal {
     }
 }
 
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Strip trailing zeros so that equal values with different scales hash alike.
+        let mut underlying = self.underlying;
+        let mut scale = self.scale;
+        while scale > 0 && underlying % 10 == 0 {
+            underlying /= 10;
+            scale -= 1;
+        }
+        underlying.hash(state);
+        scale.hash(state);
+    }
+}
+
 fn decimal2str(decimal: &Decimal) -> String {
     let mut r = format!("{}", decimal.underlying);
     while r.len() < decimal.scale() {
@@ -214,6 +243,30 @@ impl Decimal {
         self.scale as usize
     }
 
+    /// Rounds to `digits` fractional digits using `mode` and converts the result to `f64`.
+    ///
+    /// Rounding is done on the exact decimal digits, so ties are detected reliably.
+    pub fn to_f64_rounded(&self, digits: u8, mode: RoundingMode) -> f64 {
+        if digits >= self.scale {
+            return f64::from(self.clone());
+        }
+
+        let factor = FACTORS10[(self.scale - digits) as usize];
+        let mut quotient = self.underlying / factor;
+        let remainder = (self.underlying % factor).abs();
+        let round_away = match mode {
+            RoundingMode::HalfEven => {
+                remainder * 2 > factor || (remainder * 2 == factor && quotient % 2 != 0)
+            }
+            RoundingMode::HalfUp => remainder * 2 >= factor,
+            RoundingMode::Truncate => false,
+        };
+        if round_away {
+            quotient += self.underlying.signum();
+        }
+        quotient as f64 / FACTORS10[digits as usize] as f64
+    }
+
     pub(crate) fn set_scale(self, scale: u8) -> Self {
         let underlying = match scale.cmp(&self.scale) {
             Ordering::Less => {
@@ -248,6 +301,27 @@ mod test {
         assert_eq!(format!("{:?}", Decimal::of(2, 4)), "2.0000");
     }
 
+    #[test]
+    fn test_to_f64_rounded() {
+        let v = Decimal::of(2.5_f64, 1);
+        assert_eq!(v.to_f64_rounded(0, RoundingMode::HalfEven), 2.0);
+        assert_eq!(v.to_f64_rounded(0, RoundingMode::HalfUp), 3.0);
+        assert_eq!(v.to_f64_rounded(0, RoundingMode::Truncate), 2.0);
+
+        let v = Decimal::of(3.5_f64, 1);
+        assert_eq!(v.to_f64_rounded(0, RoundingMode::HalfEven), 4.0);
+        assert_eq!(v.to_f64_rounded(0, RoundingMode::HalfUp), 4.0);
+
+        let v = Decimal::of(-0.125_f64, 3);
+        assert_eq!(v.to_f64_rounded(2, RoundingMode::HalfEven), -0.12);
+        assert_eq!(v.to_f64_rounded(2, RoundingMode::HalfUp), -0.13);
+        assert_eq!(v.to_f64_rounded(2, RoundingMode::Truncate), -0.12);
+
+        let v = Decimal::of(1.26_f64, 2);
+        assert_eq!(v.to_f64_rounded(1, RoundingMode::HalfEven), 1.3);
+        assert_eq!(v.to_f64_rounded(4, RoundingMode::Truncate), 1.26);
+    }
+
     #[test]
     fn test_eq() {
         assert_eq!(Decimal::of(2.0_f64, 4), Decimal::of(2.0_f64, 4));
@@ -1,6 +1,15 @@
 use chrono::prelude::*;
 use chrono_tz::Tz;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::{
+    any,
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+    hash::Hash,
+    marker::PhantomData,
+    net::{Ipv4Addr, Ipv6Addr},
+    str,
+    time::Duration,
+};
 
 use crate::types::{Enum16, Enum8};
 use crate::{
@@ -34,16 +43,7 @@ macro_rules! from_sql_impl {
 
 impl<'a> FromSql<'a> for Decimal {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
-        match value {
-            ValueRef::Decimal(v) => Ok(v),
-            _ => {
-                let from = SqlType::from(value.clone()).to_string();
-                Err(Error::FromSql(FromSqlError::InvalidType {
-                    src: from,
-                    dst: "Decimal".into(),
-                }))
-            }
-        }
+        value.as_decimal()
     }
 }
 
@@ -91,57 +91,259 @@ impl<'a> FromSql<'a> for &'a [u8] {
     }
 }
 
+/// Reads a `String`/`FixedString`, or the label of an `Enum8`/`Enum16` value.
 impl<'a> FromSql<'a> for String {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
-        value.as_str().map(str::to_string)
+        match value {
+            ValueRef::Enum8(..) | ValueRef::Enum16(..) => value.as_enum_label().map(str::to_string),
+            ValueRef::Uuid(_) => value.as_uuid().map(|uuid| uuid.to_string()),
+            _ => value.as_str().map(str::to_string),
+        }
     }
 }
 
-impl<'a> FromSql<'a> for Ipv4Addr {
+/// Reads a flag stored as `UInt8`: `0` is `false`, `1` is `true`, anything else is an error.
+impl<'a> FromSql<'a> for bool {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
         match value {
-            ValueRef::Ipv4(ip) => Ok(Ipv4Addr::from(ip)),
+            ValueRef::UInt8(0) => Ok(false),
+            ValueRef::UInt8(1) => Ok(true),
             _ => {
-                let from = SqlType::from(value.clone()).to_string();
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "bool".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Reads a `String`/`FixedString` holding exactly one character.
+///
+/// Trailing zero bytes are ignored, so an empty `FixedString(N)` counts as empty.
+impl<'a> FromSql<'a> for char {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let s = value.as_str()?.trim_end_matches('\0');
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::FromSql(FromSqlError::NotSingleChar {
+                len: s.chars().count(),
+            })),
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<bool> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::UInt8, vs) => {
+                vs.iter().map(|v| bool::from_sql(v.clone())).collect()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
                 Err(Error::FromSql(FromSqlError::InvalidType {
                     src: from,
-                    dst: "Ipv4".into(),
+                    dst: "Vec<bool>".into(),
                 }))
             }
         }
     }
 }
 
+impl<'a> FromSql<'a> for Ipv4Addr {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        value.as_ipv4()
+    }
+}
+
 impl<'a> FromSql<'a> for Ipv6Addr {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        value.as_ipv6()
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<Ipv4Addr> {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
         match value {
-            ValueRef::Ipv6(ip) => Ok(Ipv6Addr::from(ip)),
+            ValueRef::Array(SqlType::Ipv4, vs) => {
+                vs.iter().map(|v| Ipv4Addr::from_sql(v.clone())).collect()
+            }
             _ => {
-                let from = SqlType::from(value.clone()).to_string();
+                let from = SqlType::from(value).to_string();
                 Err(Error::FromSql(FromSqlError::InvalidType {
                     src: from,
-                    dst: "Ipv6".into(),
+                    dst: "Vec<Ipv4>".into(),
                 }))
             }
         }
     }
 }
 
-impl<'a> FromSql<'a> for uuid::Uuid {
+impl<'a> FromSql<'a> for Vec<Ipv6Addr> {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
         match value {
-            ValueRef::Uuid(row) => Ok(uuid::Uuid::from_bytes(row)),
+            ValueRef::Array(SqlType::Ipv6, vs) => {
+                vs.iter().map(|v| Ipv6Addr::from_sql(v.clone())).collect()
+            }
             _ => {
-                let from = SqlType::from(value.clone()).to_string();
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Vec<Ipv6>".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// The `/64` network prefix of an `IPv6` address (its top 64 bits).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ipv6Subnet64(pub [u8; 8]);
+
+impl<'a> FromSql<'a> for Ipv6Subnet64 {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Ipv6(ip) => {
+                let mut prefix = [0_u8; 8];
+                prefix.copy_from_slice(&ip[..8]);
+                Ok(Ipv6Subnet64(prefix))
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Ipv6Subnet64".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// An `IPv4` address as the integer `IPv4StringToNum` would give, e.g. `1.2.3.4` is
+/// `0x01020304`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ipv4AsU32(pub u32);
+
+impl<'a> FromSql<'a> for Ipv4AsU32 {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        Ok(Ipv4AsU32(u32::from(value.as_ipv4()?)))
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<Ipv4AsU32> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::Ipv4, vs) => {
+                vs.iter().map(|v| Ipv4AsU32::from_sql(v.clone())).collect()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Vec<Ipv4AsU32>".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// An `IPv6` address as a big-endian 128-bit integer, the first octet being the
+/// most significant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ipv6AsU128(pub u128);
+
+impl<'a> FromSql<'a> for Ipv6AsU128 {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        Ok(Ipv6AsU128(u128::from(value.as_ipv6()?)))
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<Ipv6AsU128> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::Ipv6, vs) => {
+                vs.iter().map(|v| Ipv6AsU128::from_sql(v.clone())).collect()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
                 Err(Error::FromSql(FromSqlError::InvalidType {
                     src: from,
-                    dst: "Uuid".into(),
+                    dst: "Vec<Ipv6AsU128>".into(),
                 }))
             }
         }
     }
 }
 
+/// A `String` is parsed as UUID text, unless it's 16 bytes that aren't all printable
+/// ASCII: those are taken as the raw bytes of a UUID stored in a `FixedString(16)`.
+impl<'a> FromSql<'a> for uuid::Uuid {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::String(bytes) if bytes.len() == 16 && !is_printable_ascii(bytes) => {
+                uuid_from_slice(bytes, SqlType::String)
+            }
+            ValueRef::String(_) => {
+                uuid::Uuid::parse_str(value.as_str()?).map_err(|_| invalid_uuid(SqlType::String))
+            }
+            ValueRef::Array(SqlType::UInt8, vs) => {
+                let bytes: Vec<u8> = vs.iter().cloned().map(u8::from).collect();
+                uuid_from_slice(&bytes, SqlType::Array(SqlType::UInt8.into()))
+            }
+            _ => value.as_uuid(),
+        }
+    }
+}
+
+// `T`'s name without module paths, e.g. `Vec<String>` rather than
+// `alloc::vec::Vec<alloc::string::String>`.
+fn short_type_name<T>() -> String {
+    let full = any::type_name::<T>();
+    let mut result = String::with_capacity(full.len());
+    let mut path_start = 0;
+    let mut chars = full.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            result.truncate(path_start);
+            continue;
+        }
+        result.push(c);
+        if !(c.is_alphanumeric() || c == '_') {
+            path_start = result.len();
+        }
+    }
+    result
+}
+
+// A `FixedString(16)` or `Array(UInt8)` may hold the raw bytes of a UUID.
+fn uuid_from_slice(bytes: &[u8], src: SqlType) -> FromSqlResult<uuid::Uuid> {
+    uuid::Uuid::from_slice(bytes).map_err(|_| invalid_uuid(src))
+}
+
+fn invalid_uuid(src: SqlType) -> Error {
+    Error::FromSql(FromSqlError::InvalidType {
+        src: src.to_string(),
+        dst: "Uuid".into(),
+    })
+}
+
+fn is_printable_ascii(bytes: &[u8]) -> bool {
+    bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ')
+}
+
+/// A UUID as a big-endian 128-bit integer, read from any source `uuid::Uuid` accepts.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UuidU128(pub u128);
+
+impl<'a> FromSql<'a> for UuidU128 {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        Ok(UuidU128(uuid::Uuid::from_sql(value)?.as_u128()))
+    }
+}
+
 macro_rules! from_sql_vec_impl {
     ( $( $t:ty: $k:pat => $f:expr ),* ) => {
         $(
@@ -173,9 +375,30 @@ macro_rules! from_sql_vec_impl {
 
 from_sql_vec_impl! {
     &'a str: SqlType::String => |v| v.as_str(),
-    String: SqlType::String => |v| v.as_string(),
     Date<Tz>: SqlType::Date => |z| Ok(z.into()),
-    DateTime<Tz>: SqlType::DateTime(_) => |z| Ok(z.into())
+    DateTime<Tz>: SqlType::DateTime(_) => |z| Ok(z.into()),
+    DateTime<Utc>: SqlType::DateTime(_) => DateTime::<Utc>::from_sql,
+    DateTime<Local>: SqlType::DateTime(_) => DateTime::<Local>::from_sql,
+    Decimal: SqlType::Decimal(..) => Decimal::from_sql
+}
+
+impl<'a> FromSql<'a> for Vec<String> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::String, vs) => vs.iter().map(|v| v.as_string()).collect(),
+            ValueRef::Array(SqlType::Enum8(_), vs) | ValueRef::Array(SqlType::Enum16(_), vs) => vs
+                .iter()
+                .map(|v| v.as_enum_label().map(str::to_string))
+                .collect(),
+            _ => {
+                let from = SqlType::from(value.clone()).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Vec<String>".into(),
+                }))
+            }
+        }
+    }
 }
 
 impl<'a> FromSql<'a> for Vec<u8> {
@@ -209,7 +432,8 @@ macro_rules! from_sql_vec_impl {
                         }
                         _ => {
                             let from = SqlType::from(value.clone()).to_string();
-                            Err(Error::FromSql(FromSqlError::InvalidType { src: from, dst: stringify!($t).into() }))
+                            let dst = concat!("Vec<", stringify!($t), ">");
+                            Err(Error::FromSql(FromSqlError::InvalidType { src: from, dst: dst.into() }))
                         }
                     }
                 }
@@ -223,10 +447,12 @@ from_sql_vec_impl! {
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
 
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     f32: Float32,
     f64: Float64
@@ -256,6 +482,152 @@ impl<'a, T> FromSql<'a> for Option<T>
     }
 }
 
+impl<'a, K, V> FromSql<'a> for HashMap<K, V>
+where
+    K: FromSql<'a> + Eq + Hash,
+    V: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Map(_, _, entries) => entries
+                .iter()
+                .map(|(k, v)| Ok((K::from_sql(k.clone())?, V::from_sql(v.clone())?)))
+                .collect(),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "HashMap".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Keys are kept in order, so e.g. a sparse time-series stored as
+/// `Map(DateTime, Float64)` reads into `BTreeMap<i64, f64>` (or
+/// `BTreeMap<UnixTimestamp, f64>`) sorted chronologically.
+///
+/// A `DateTime`/`DateTime64` key that doesn't read as `K` directly is read as its
+/// epoch seconds, i.e. as an `Int64`.
+impl<'a, K, V> FromSql<'a> for BTreeMap<K, V>
+where
+    K: FromSql<'a> + Ord,
+    V: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Map(_, _, entries) => entries
+                .iter()
+                .map(|(k, v)| Ok((time_series_key(k.clone())?, V::from_sql(v.clone())?)))
+                .collect(),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "BTreeMap".into(),
+                }))
+            }
+        }
+    }
+}
+
+fn time_series_key<'a, K: FromSql<'a>>(key: ValueRef<'a>) -> FromSqlResult<K> {
+    match (K::from_sql(key.clone()), &key) {
+        (
+            Err(Error::FromSql(FromSqlError::InvalidType { .. })),
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..),
+        ) => K::from_sql(ValueRef::Int64(UnixTimestamp::from_sql(key)?.0)),
+        (result, _) => result,
+    }
+}
+
+/// Captures a conversion failure instead of propagating it, so the caller can
+/// handle bad values individually (e.g. `Vec<Result<u32>>`).
+impl<'a, T> FromSql<'a> for Result<T>
+where
+    T: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        Ok(T::from_sql(value))
+    }
+}
+
+impl<'a, T> FromSql<'a> for Vec<Result<T>>
+where
+    T: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(_, vs) => Ok(vs.iter().map(|v| T::from_sql(v.clone())).collect()),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: format!("Vec<Result<{}>>", short_type_name::<T>()).into(),
+                }))
+            }
+        }
+    }
+}
+
+/// An `Array(String)` is accepted too, so that arrays of binary blobs read as
+/// `Vec<Vec<u8>>`, one byte vector per string.
+impl<'a, T> FromSql<'a> for Vec<Vec<T>>
+where
+    Vec<T>: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::Array(_), vs) | ValueRef::Array(SqlType::String, vs) => {
+                vs.iter().map(|v| Vec::<T>::from_sql(v.clone())).collect()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: format!("Vec<Vec<{}>>", short_type_name::<T>()).into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Reads an `Array(T)` of exactly `N` elements into a fixed-size array.
+impl<'a, T, const N: usize> FromSql<'a> for [T; N]
+where
+    T: FromSql<'a>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(_, vs) if vs.len() != N => {
+                Err(Error::FromSql(FromSqlError::ArrayLength {
+                    item: short_type_name::<T>().into(),
+                    expected: N,
+                    actual: vs.len(),
+                }))
+            }
+            ValueRef::Array(_, vs) => {
+                let values = vs
+                    .iter()
+                    .map(|v| T::from_sql(v.clone()))
+                    .collect::<FromSqlResult<Vec<T>>>()?;
+                match <[T; N]>::try_from(values) {
+                    Ok(array) => Ok(array),
+                    Err(_) => unreachable!(),
+                }
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: format!("[{}; {}]", short_type_name::<T>(), N).into(),
+                }))
+            }
+        }
+    }
+}
+
 impl<'a> FromSql<'a> for Date<Tz> {
     fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
         match value {
@@ -296,41 +668,1931 @@ impl<'a> FromSql<'a> for DateTime<Tz> {
     }
 }
 
-from_sql_impl! {
-    u8: UInt8,
-    u16: UInt16,
-    u32: UInt32,
-    u64: UInt64,
+macro_rules! from_sql_datetime_impl {
+    ( $( $z:ty: $tz:expr ),* ) => {
+        $(
+            impl<'a> FromSql<'a> for DateTime<$z> {
+                fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+                    match value {
+                        ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                            Ok(DateTime::<Tz>::from_sql(value)?.with_timezone(&$tz))
+                        }
+                        _ => {
+                            let from = SqlType::from(value).to_string();
+                            Err(Error::FromSql(FromSqlError::InvalidType {
+                                src: from,
+                                dst: concat!("DateTime<", stringify!($z), ">").into(),
+                            }))
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
 
-    i8: Int8,
-    i16: Int16,
-    i32: Int32,
-    i64: Int64,
+from_sql_datetime_impl! {
+    Utc: Utc,
+    Local: Local
+}
 
-    f32: Float32,
-    f64: Float64
+impl<'a> FromSql<'a> for NaiveDate {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Date(v, tz) => tz
+                .timestamp_opt(i64::from(v) * 24 * 3600, 0)
+                .single()
+                .map(|time| time.date_naive())
+                .ok_or(Error::FromSql(FromSqlError::OutOfRange)),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "NaiveDate".into(),
+                }))
+            }
+        }
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use crate::types::{from_sql::FromSql, ValueRef};
+impl<'a> FromSql<'a> for NaiveDateTime {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                Ok(DateTime::<Tz>::from_sql(value)?.naive_local())
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "NaiveDateTime".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Whole seconds since the Unix epoch of a `DateTime`/`DateTime64` value.
+///
+/// Sub-second parts of `DateTime64` are dropped (rounding towards negative infinity).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UnixTimestamp(pub i64);
+
+impl<'a> FromSql<'a> for UnixTimestamp {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                Ok(UnixTimestamp(ProtoTimestamp::from_sql(value)?.seconds))
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "UnixTimestamp".into(),
+                }))
+            }
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for Vec<UnixTimestamp> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::DateTime(_), vs) => vs
+                .iter()
+                .map(|v| UnixTimestamp::from_sql(v.clone()))
+                .collect(),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Vec<UnixTimestamp>".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// `DateTime64` ticks rescaled to precision `P` (e.g. `P = 3` for milliseconds since the
+/// epoch), regardless of the column precision.
+///
+/// Down-scaling fails with `FromSqlError::OutOfRange` unless the dropped digits are zero.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DateTime64As<const P: u32>(pub i64);
+
+impl<'a, const P: u32> FromSql<'a> for DateTime64As<P> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let (ticks, precision) = match value {
+            ValueRef::DateTime64(v, params) => (v, params.0),
+            ValueRef::DateTime(v, _) => (i64::from(v), 0),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "DateTime64As".into(),
+                }));
+            }
+        };
+
+        let rescaled = if P >= precision {
+            10_i64
+                .checked_pow(P - precision)
+                .and_then(|factor| ticks.checked_mul(factor))
+        } else {
+            10_i64
+                .checked_pow(precision - P)
+                .filter(|factor| ticks % factor == 0)
+                .map(|factor| ticks / factor)
+        };
+
+        match rescaled {
+            Some(v) => Ok(DateTime64As(v)),
+            None => Err(Error::FromSql(FromSqlError::OutOfRange)),
+        }
+    }
+}
+
+/// Seconds and nanoseconds since the Unix epoch, laid out like protobuf's `Timestamp`.
+///
+/// `nanos` is always in `0..1_000_000_000`, so pre-epoch instants have a negative
+/// `seconds` and a positive fractional part.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProtoTimestamp {
+    pub seconds: i64,
+    pub nanos: i32,
+}
+
+impl<'a> FromSql<'a> for ProtoTimestamp {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::DateTime(v, _) => Ok(ProtoTimestamp {
+                seconds: i64::from(v),
+                nanos: 0,
+            }),
+            ValueRef::DateTime64(v, params) => {
+                let (precision, _) = *params;
+                if precision > 9 {
+                    return Err(Error::FromSql(FromSqlError::OutOfRange));
+                }
+
+                let base10: i64 = 10;
+                let units = base10.pow(precision);
+                Ok(ProtoTimestamp {
+                    seconds: v.div_euclid(units),
+                    nanos: (v.rem_euclid(units) * base10.pow(9 - precision)) as i32,
+                })
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "ProtoTimestamp".into(),
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl<'a> FromSql<'a> for jiff::Timestamp {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let ProtoTimestamp { seconds, nanos } = match ProtoTimestamp::from_sql(value.clone()) {
+            Ok(timestamp) => timestamp,
+            Err(Error::FromSql(FromSqlError::InvalidType { .. })) => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "jiff::Timestamp".into(),
+                }));
+            }
+            Err(err) => return Err(err),
+        };
+
+        match jiff::Timestamp::new(seconds, nanos) {
+            Ok(timestamp) => Ok(timestamp),
+            Err(_) => Err(Error::FromSql(FromSqlError::OutOfRange)),
+        }
+    }
+}
+
+/// Sign and magnitude of the bits of `decimal` as a fixed-point number with `frac` fractional
+/// bits, rounded to the nearest representable value (ties away from zero).
+#[cfg(feature = "fixed")]
+fn decimal_to_fixed_bits(decimal: &Decimal, frac: u32) -> Option<(bool, u128)> {
+    let negative = decimal.underlying < 0;
+    let magnitude = u128::from(decimal.underlying.unsigned_abs());
+    let denominator = 10_u128.pow(u32::from(decimal.scale));
+
+    let int_part = magnitude / denominator;
+    let mut remainder = magnitude % denominator;
+    let mut frac_part: u128 = 0;
+    for _ in 0..frac {
+        remainder *= 2;
+        frac_part <<= 1;
+        if remainder >= denominator {
+            frac_part |= 1;
+            remainder -= denominator;
+        }
+    }
+
+    let int_bits = match frac {
+        128 if int_part == 0 => 0,
+        128 => return None,
+        _ => int_part.checked_mul(1 << frac)?,
+    };
+    let mut bits = int_bits | frac_part;
+    if remainder * 2 >= denominator {
+        bits = bits.checked_add(1)?;
+    }
+    Some((negative, bits))
+}
+
+/// Reads a `Decimal` into the nearest `FixedI128` value; fails if it doesn't fit.
+#[cfg(feature = "fixed")]
+impl<'a, Frac: fixed::types::extra::LeEqU128> FromSql<'a> for fixed::FixedI128<Frac> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Decimal(v) => match decimal_to_fixed_bits(&v, Frac::U32) {
+                Some((false, bits)) if bits <= i128::MAX as u128 => {
+                    Ok(Self::from_bits(bits as i128))
+                }
+                Some((true, bits)) if bits <= i128::MIN.unsigned_abs() => {
+                    Ok(Self::from_bits((bits as i128).wrapping_neg()))
+                }
+                _ => Err(Error::FromSql(FromSqlError::OutOfRange)),
+            },
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "fixed::FixedI128".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Reads a non-negative `Decimal` into the nearest `FixedU128` value; fails if it doesn't fit.
+#[cfg(feature = "fixed")]
+impl<'a, Frac: fixed::types::extra::LeEqU128> FromSql<'a> for fixed::FixedU128<Frac> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Decimal(v) => match decimal_to_fixed_bits(&v, Frac::U32) {
+                Some((false, bits)) => Ok(Self::from_bits(bits)),
+                Some((true, 0)) => Ok(Self::from_bits(0)),
+                _ => Err(Error::FromSql(FromSqlError::OutOfRange)),
+            },
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "fixed::FixedU128".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Elements of an array rendered with `Display` and joined by `SEP` (`,` by default).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Joined<const SEP: char = ','>(pub String);
+
+impl<'a, const SEP: char> FromSql<'a> for Joined<SEP> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(_, vs) => {
+                let cells: Vec<String> = vs.iter().map(|v| format!("{}", v)).collect();
+                Ok(Joined(cells.join(SEP.encode_utf8(&mut [0; 4]))))
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Joined".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Elements of an `Array(String)` joined by newlines into one blob.
+///
+/// Unlike `Joined<'\n'>` this accepts only string arrays and fails on non-UTF-8 elements.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LinesJoined(pub String);
+
+impl<'a> FromSql<'a> for LinesJoined {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Array(SqlType::String, _) => {
+                Ok(LinesJoined(Vec::<&str>::from_sql(value)?.join("\n")))
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "LinesJoined".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Lowercase hex encoding of a `String`/`FixedString` or `Array(UInt8)` value.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Hex(pub String);
+
+impl<'a> FromSql<'a> for Hex {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let bytes = match value {
+            ValueRef::String(_) | ValueRef::Array(SqlType::UInt8, _) => Vec::<u8>::from_sql(value)?,
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Hex".into(),
+                }));
+            }
+        };
+
+        let mut result = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            result.push_str(&format!("{:02x}", byte));
+        }
+        Ok(Hex(result))
+    }
+}
+
+/// A number stored as text in a `String` column, parsed with `T::from_str`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseNum<T>(pub T);
+
+impl<'a, T> FromSql<'a> for ParseNum<T>
+where
+    T: str::FromStr,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::String(_) => match value.as_str()?.parse() {
+                Ok(v) => Ok(ParseNum(v)),
+                Err(_) => Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: SqlType::String.to_string(),
+                    dst: short_type_name::<T>().into(),
+                })),
+            },
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "ParseNum".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// A `Decimal` rescaled to `S` decimal places, i.e. `value * 10^S` as an integer.
+///
+/// Upscaling fails only on overflow; downscaling fails unless the dropped digits are zero.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ScaledI128<const S: u8>(pub i128);
+
+impl<'a, const S: u8> FromSql<'a> for ScaledI128<S> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let decimal = match value {
+            ValueRef::Decimal(v) => v,
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "ScaledI128".into(),
+                }));
+            }
+        };
+
+        let underlying = i128::from(decimal.underlying);
+        let scaled = if S >= decimal.scale {
+            10_i128
+                .checked_pow(u32::from(S - decimal.scale))
+                .and_then(|factor| underlying.checked_mul(factor))
+        } else {
+            let factor = 10_i128.pow(u32::from(decimal.scale - S));
+            if underlying % factor == 0 {
+                Some(underlying / factor)
+            } else {
+                None
+            }
+        };
+
+        match scaled {
+            Some(v) => Ok(ScaledI128(v)),
+            None => Err(Error::FromSql(FromSqlError::OutOfRange)),
+        }
+    }
+}
+
+/// A monetary amount read from a `Decimal`, kept exactly as `amount / 10^scale`.
+///
+/// Equality compares the raw fields, so `1.50` and `1.5` at different scales differ.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+    pub amount: i128,
+    pub scale: u8,
+}
+
+impl Money {
+    /// Exact sum at the larger of the two scales, or `None` on overflow.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        let (a, b, scale) = Money::align(self, other)?;
+        a.checked_add(b).map(|amount| Money { amount, scale })
+    }
+
+    /// Exact difference at the larger of the two scales, or `None` on overflow.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        let (a, b, scale) = Money::align(self, other)?;
+        a.checked_sub(b).map(|amount| Money { amount, scale })
+    }
+
+    fn align(a: Money, b: Money) -> Option<(i128, i128, u8)> {
+        let scale = a.scale.max(b.scale);
+        Some((a.rescale(scale)?, b.rescale(scale)?, scale))
+    }
+
+    fn rescale(self, scale: u8) -> Option<i128> {
+        10_i128
+            .checked_pow(u32::from(scale - self.scale))
+            .and_then(|factor| self.amount.checked_mul(factor))
+    }
+}
+
+impl<'a> FromSql<'a> for Money {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Decimal(v) => Ok(Money {
+                amount: i128::from(v.underlying),
+                scale: v.scale,
+            }),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Money".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// Components of a Snowflake-style ID stored in a `UInt64` column.
+///
+/// The ID is laid out as `timestamp | worker | sequence` from the high bits down, with
+/// `WORKER_BITS` and `SEQUENCE_BITS` low bits for the last two parts and the timestamp
+/// counted in milliseconds since `EPOCH_MS`. The defaults match Twitter's layout.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Snowflake<
+    const WORKER_BITS: u32 = 10,
+    const SEQUENCE_BITS: u32 = 12,
+    const EPOCH_MS: u64 = 1_288_834_974_657,
+> {
+    pub timestamp_ms: u64,
+    pub worker: u16,
+    pub sequence: u16,
+}
+
+impl<'a, const WORKER_BITS: u32, const SEQUENCE_BITS: u32, const EPOCH_MS: u64> FromSql<'a>
+    for Snowflake<WORKER_BITS, SEQUENCE_BITS, EPOCH_MS>
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let id = match value {
+            ValueRef::UInt64(v) => v,
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Snowflake".into(),
+                }));
+            }
+        };
+
+        if WORKER_BITS > 16 || SEQUENCE_BITS > 16 {
+            return Err(Error::FromSql(FromSqlError::OutOfRange));
+        }
+
+        let sequence = id & ((1 << SEQUENCE_BITS) - 1);
+        let worker = (id >> SEQUENCE_BITS) & ((1 << WORKER_BITS) - 1);
+        let timestamp_ms = (id >> (WORKER_BITS + SEQUENCE_BITS))
+            .checked_add(EPOCH_MS)
+            .ok_or(Error::FromSql(FromSqlError::OutOfRange))?;
+
+        Ok(Snowflake {
+            timestamp_ms,
+            worker: worker as u16,
+            sequence: sequence as u16,
+        })
+    }
+}
+
+/// A float that is neither NaN nor infinite; other values fail with
+/// `FromSqlError::NonFinite`.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Finite<T>(pub T);
+
+macro_rules! finite_impl {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<'a> FromSql<'a> for Finite<$t> {
+                fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+                    let v = <$t>::from_sql(value)?;
+                    if v.is_finite() {
+                        Ok(Finite(v))
+                    } else {
+                        Err(Error::FromSql(FromSqlError::NonFinite))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+finite_impl! { f32, f64 }
+
+/// A whole number of seconds stored as `T`, read as a `Duration`.
+///
+/// Negative values fail with `FromSqlError::OutOfRange`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Seconds<T> {
+    pub duration: Duration,
+    unit: PhantomData<T>,
+}
+
+/// A whole number of milliseconds stored as `T`, read as a `Duration`.
+///
+/// Negative values fail with `FromSqlError::OutOfRange`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Millis<T> {
+    pub duration: Duration,
+    unit: PhantomData<T>,
+}
+
+macro_rules! duration_impl {
+    ( $w:ident, $f:ident; $( $t:ident: $k:ident ),* ) => {
+        impl<T> From<$w<T>> for Duration {
+            fn from(v: $w<T>) -> Duration {
+                v.duration
+            }
+        }
+
+        $(
+            impl<'a> FromSql<'a> for $w<$t> {
+                fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+                    let v = $t::from_sql(value)?;
+                    let n = u64::try_from(i128::from(v))
+                        .map_err(|_| Error::FromSql(FromSqlError::OutOfRange))?;
+                    Ok($w {
+                        duration: Duration::$f(n),
+                        unit: PhantomData,
+                    })
+                }
+            }
+
+            impl<'a> FromSql<'a> for Vec<$w<$t>> {
+                fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+                    match value {
+                        ValueRef::Array(SqlType::$k, vs) => {
+                            vs.iter().map(|v| $w::<$t>::from_sql(v.clone())).collect()
+                        }
+                        _ => {
+                            let from = SqlType::from(value).to_string();
+                            Err(Error::FromSql(FromSqlError::InvalidType {
+                                src: from,
+                                dst: concat!("Vec<", stringify!($w), "<", stringify!($t), ">>").into(),
+                            }))
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+duration_impl! {
+    Seconds, from_secs;
+    u8: UInt8, u16: UInt16, u32: UInt32, u64: UInt64,
+    i8: Int8, i16: Int16, i32: Int32, i64: Int64
+}
+
+duration_impl! {
+    Millis, from_millis;
+    u8: UInt8, u16: UInt16, u32: UInt32, u64: UInt64,
+    i8: Int8, i16: Int16, i32: Int32, i64: Int64
+}
+
+/// ISO 8601 year and week of a `Date`/`DateTime` in the column timezone, as `YYYYWW`.
+///
+/// The year is the ISO week-numbering year, so e.g. 2021-01-01 maps to `202053`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IsoWeekKey(pub u32);
+
+impl<'a> FromSql<'a> for IsoWeekKey {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let week = match value {
+            ValueRef::Date(..) => NaiveDate::from_sql(value)?.iso_week(),
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                DateTime::<Tz>::from_sql(value)?.iso_week()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "IsoWeekKey".into(),
+                }));
+            }
+        };
+        Ok(IsoWeekKey(week.year() as u32 * 100 + week.week()))
+    }
+}
+
+/// Calendar quarter of a `Date`/`DateTime` in the column timezone, e.g. `"2023-Q2"`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct QuarterKey(pub String);
+
+impl<'a> FromSql<'a> for QuarterKey {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let date = match value {
+            ValueRef::Date(..) => NaiveDate::from_sql(value)?,
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                NaiveDateTime::from_sql(value)?.date()
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "QuarterKey".into(),
+                }));
+            }
+        };
+        let quarter = date.month0() / 3 + 1;
+        Ok(QuarterKey(format!("{}-Q{}", date.year(), quarter)))
+    }
+}
+
+/// Day of the week of a `Date`/`DateTime` in the column timezone.
+///
+/// Use `Weekday::num_days_from_monday` for a Monday = 0 index or
+/// `Weekday::num_days_from_sunday` for a Sunday = 0 index.
+impl<'a> FromSql<'a> for Weekday {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Date(..) => Ok(NaiveDate::from_sql(value)?.weekday()),
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                Ok(DateTime::<Tz>::from_sql(value)?.weekday())
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Weekday".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// An integer that must lie within `MIN..=MAX`; values outside the bounds fail with
+/// `FromSqlError::OutOfRange`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InRange<const MIN: i64, const MAX: i64>(pub i64);
+
+impl<'a, const MIN: i64, const MAX: i64> FromSql<'a> for InRange<MIN, MAX> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let v = match value {
+            ValueRef::UInt8(v) => i64::from(v),
+            ValueRef::UInt16(v) => i64::from(v),
+            ValueRef::UInt32(v) => i64::from(v),
+            ValueRef::UInt64(v) => match i64::try_from(v) {
+                Ok(v) => v,
+                Err(_) => return Err(Error::FromSql(FromSqlError::OutOfRange)),
+            },
+            ValueRef::Int8(v) => i64::from(v),
+            ValueRef::Int16(v) => i64::from(v),
+            ValueRef::Int32(v) => i64::from(v),
+            ValueRef::Int64(v) => v,
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "InRange".into(),
+                }));
+            }
+        };
+
+        if (MIN..=MAX).contains(&v) {
+            Ok(InRange(v))
+        } else {
+            Err(Error::FromSql(FromSqlError::OutOfRange))
+        }
+    }
+}
+
+/// An integer of any width converted into `T`, for reading e.g. a `UInt16` column as
+/// `u32`; values that don't fit fail with `FromSqlError::OutOfRange`.
+///
+/// Plain integer targets stay strict and only accept their exact column type.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Widen<T>(pub T);
+
+impl<'a, T> FromSql<'a> for Widen<T>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        let converted = match value {
+            ValueRef::UInt8(v) => T::try_from(u128::from(v)).ok(),
+            ValueRef::UInt16(v) => T::try_from(u128::from(v)).ok(),
+            ValueRef::UInt32(v) => T::try_from(u128::from(v)).ok(),
+            ValueRef::UInt64(v) => T::try_from(u128::from(v)).ok(),
+            ValueRef::UInt128(v) => T::try_from(v).ok(),
+            ValueRef::Int8(v) => T::try_from(i128::from(v)).ok(),
+            ValueRef::Int16(v) => T::try_from(i128::from(v)).ok(),
+            ValueRef::Int32(v) => T::try_from(i128::from(v)).ok(),
+            ValueRef::Int64(v) => T::try_from(i128::from(v)).ok(),
+            ValueRef::Int128(v) => T::try_from(v).ok(),
+            _ => {
+                let from = SqlType::from(value).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Widen".into(),
+                }));
+            }
+        };
+
+        converted
+            .map(Widen)
+            .ok_or(Error::FromSql(FromSqlError::OutOfRange))
+    }
+}
+
+/// Table of integer codes for a type stored as plain numbers, see `CodeMapped`.
+pub trait CodeTable: Clone + 'static {
+    const CODES: &'static [(u32, Self)];
+}
+
+/// A `UInt32` code mapped through `T::CODES`; unknown codes are an error.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CodeMapped<T>(pub T);
+
+impl<'a, T: CodeTable> FromSql<'a> for CodeMapped<T> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::UInt32(code) => match T::CODES.iter().find(|(c, _)| *c == code) {
+                Some((_, v)) => Ok(CodeMapped(v.clone())),
+                None => Err(Error::FromSql(FromSqlError::UnknownCode { code })),
+            },
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "CodeMapped".into(),
+                }))
+            }
+        }
+    }
+}
+
+/// A `UInt8` code mapped through `T::CODES` into an ordered category.
+///
+/// Ordering follows `T`'s `Ord`, not the stored code, so the codes can be
+/// assigned independently of how the categories should sort.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OrderedCategory<T: Ord>(pub T);
+
+impl<'a, T: CodeTable + Ord> FromSql<'a> for OrderedCategory<T> {
+    fn from_sql(value: ValueRef<'a>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::UInt8(code) => {
+                let code = u32::from(code);
+                match T::CODES.iter().find(|(c, _)| *c == code) {
+                    Some((_, v)) => Ok(OrderedCategory(v.clone())),
+                    None => Err(Error::FromSql(FromSqlError::UnknownCode { code })),
+                }
+            }
+            _ => {
+                let from = SqlType::from(value).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "OrderedCategory".into(),
+                }))
+            }
+        }
+    }
+}
+
+from_sql_impl! {
+    u8: UInt8,
+    u16: UInt16,
+    u32: UInt32,
+    u64: UInt64,
+    u128: UInt128,
+
+    i8: Int8,
+    i16: Int16,
+    i32: Int32,
+    i64: Int64,
+    i128: Int128,
+
+    f32: Float32,
+    f64: Float64
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        io::Cursor,
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::Arc,
+        time::Duration,
+    };
+
+    use chrono::prelude::*;
+    use chrono_tz::Tz;
+
+    use crate::{
+        errors::{Error, FromSqlError, Result},
+        types::{
+            column::{fixed_string::FixedStringColumnData, BoxColumnWrapper, ColumnData},
+            from_sql::{
+                short_type_name, CodeMapped, CodeTable, DateTime64As, Finite, FromSql, Hex,
+                InRange, Ipv4AsU32, Ipv6AsU128, Ipv6Subnet64, IsoWeekKey, Joined, LinesJoined,
+                Millis, Money, OrderedCategory, ParseNum, ProtoTimestamp, QuarterKey, ScaledI128,
+                Seconds, Snowflake, UnixTimestamp, UuidU128, Widen,
+            },
+            Block, DateTimeType, Decimal, Enum8, Simple, SqlType, Value, ValueRef,
+        },
+    };
+
+    #[test]
+    fn test_u8() {
+        let v = ValueRef::from(42_u8);
+        let actual = u8::from_sql(v).unwrap();
+        assert_eq!(actual, 42_u8);
+    }
+
+    #[test]
+    fn test_128_bit() {
+        for &x in &[i128::MIN, -1, 0, i128::MAX] {
+            assert_eq!(i128::from_sql(ValueRef::from(x)).unwrap(), x);
+        }
+        for &x in &[u128::MIN, u128::MAX] {
+            assert_eq!(u128::from_sql(ValueRef::from(x)).unwrap(), x);
+        }
+
+        let (min, max) = (ValueRef::Int128(i128::MIN), ValueRef::Int128(i128::MAX));
+        let v = ValueRef::Array(SqlType::Int128.into(), Arc::new(vec![min, max]));
+        assert_eq!(
+            Vec::<i128>::from_sql(v).unwrap(),
+            vec![i128::MIN, i128::MAX]
+        );
+
+        let v = ValueRef::Array(
+            SqlType::UInt128.into(),
+            Arc::new(vec![ValueRef::UInt128(u128::MAX)]),
+        );
+        assert_eq!(Vec::<u128>::from_sql(v).unwrap(), vec![u128::MAX]);
+
+        match i128::from_sql(ValueRef::UInt128(1)) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::UInt128 cannot be cast to i128.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_bool() {
+        assert!(!bool::from_sql(ValueRef::UInt8(0)).unwrap());
+        assert!(bool::from_sql(ValueRef::UInt8(1)).unwrap());
+        assert!(bool::from_sql(ValueRef::UInt8(2)).is_err());
+        assert!(bool::from_sql(ValueRef::Int8(1)).is_err());
+
+        let v = ValueRef::Array(
+            SqlType::UInt8.into(),
+            Arc::new(vec![ValueRef::UInt8(1), ValueRef::UInt8(0)]),
+        );
+        assert_eq!(Vec::<bool>::from_sql(v).unwrap(), vec![true, false]);
+    }
+
+    #[test]
+    fn test_vec_of_decimals() {
+        let item_type: &'static SqlType = SqlType::Decimal(18, 4).into();
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(SqlType::Array(item_type), Tz::Zulu, 1)
+                .unwrap();
+        let amounts = vec![Decimal::of(1.5_f64, 4), Decimal::of(0.25_f64, 4)];
+        column.push(Value::Array(
+            item_type,
+            Arc::new(amounts.iter().cloned().map(Value::from).collect()),
+        ));
+
+        let actual = Vec::<Decimal>::from_sql(column.at(0)).unwrap();
+        assert_eq!(actual, amounts);
+        assert!(actual.iter().all(|v| v.scale() == 4));
+        assert_eq!(actual[1].to_string(), "0.2500");
+
+        let v = ValueRef::Array(SqlType::UInt32.into(), Arc::new(vec![ValueRef::UInt32(1)]));
+        match Vec::<Decimal>::from_sql(v) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::Array(UInt32) cannot be cast to Vec<Decimal>.`"
+                    .to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_vec_of_ips() {
+        let v = ValueRef::Array(
+            SqlType::Ipv4.into(),
+            Arc::new(vec![
+                ValueRef::Ipv4([10, 0, 0, 1]),
+                ValueRef::Ipv4([192, 168, 1, 1]),
+                ValueRef::Ipv4([8, 8, 8, 8]),
+            ]),
+        );
+        let actual = Vec::<Ipv4Addr>::from_sql(v).unwrap();
+        let expected = vec![
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(192, 168, 1, 1),
+            Ipv4Addr::new(8, 8, 8, 8),
+        ];
+        assert_eq!(actual, expected);
+
+        let v = ValueRef::Array(
+            SqlType::Ipv6.into(),
+            Arc::new(vec![ValueRef::Ipv6([0; 16])]),
+        );
+        let actual = Vec::<Ipv6Addr>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![Ipv6Addr::UNSPECIFIED]);
+
+        let v = ValueRef::Array(SqlType::UInt32.into(), Arc::new(vec![ValueRef::UInt32(1)]));
+        match Vec::<Ipv4Addr>::from_sql(v) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::Array(UInt32) cannot be cast to Vec<Ipv4>.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_vec_of_128_bit() {
+        let v = ValueRef::Array(
+            SqlType::Int128.into(),
+            Arc::new(vec![ValueRef::Int128(-170_141_183), ValueRef::Int128(42)]),
+        );
+        assert_eq!(Vec::<i128>::from_sql(v).unwrap(), vec![-170_141_183, 42]);
+
+        let v = ValueRef::Array(
+            SqlType::UInt128.into(),
+            Arc::new(vec![ValueRef::UInt128(1), ValueRef::UInt128(u128::MAX)]),
+        );
+        assert_eq!(Vec::<u128>::from_sql(v).unwrap(), vec![1, u128::MAX]);
+
+        let v = ValueRef::Array(SqlType::Int64.into(), Arc::new(vec![ValueRef::Int64(1)]));
+        match Vec::<i128>::from_sql(v) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::Array(Int64) cannot be cast to Vec<i128>.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+
+        let v = ValueRef::Array(SqlType::Int128.into(), Arc::new(vec![ValueRef::Int128(1)]));
+        match Vec::<u128>::from_sql(v) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::Array(Int128) cannot be cast to Vec<u128>.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_bad_convert() {
+        let v = ValueRef::from(42_u16);
+        match u32::from_sql(v) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::UInt16 cannot be cast to u32.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_widen() {
+        let block = Block::<Simple>::new()
+            .column("small_col", vec![7_u16, u16::MAX])
+            .column("big_col", vec![i64::from(i32::MIN), i64::MAX]);
+        let rows: Vec<_> = block.rows().collect();
+
+        let Widen(v) = rows[1].get::<Widen<u64>, _>("small_col").unwrap();
+        assert_eq!(v, 65535);
+        let Widen(v) = rows[0].get::<Widen<i32>, _>("big_col").unwrap();
+        assert_eq!(v, i32::MIN);
+
+        assert_eq!(
+            Widen::<u32>::from_sql(ValueRef::UInt16(42)).unwrap(),
+            Widen(42)
+        );
+        assert!(u32::from_sql(ValueRef::UInt16(42)).is_err());
+
+        match Widen::<i32>::from_sql(ValueRef::Int64(i64::MAX)) {
+            Err(Error::FromSql(FromSqlError::OutOfRange)) => {}
+            _ => panic!("should overflow"),
+        }
+        assert!(Widen::<u8>::from_sql(ValueRef::Int8(-1)).is_err());
+        assert!(Widen::<u64>::from_sql(ValueRef::Float64(1.0)).is_err());
+    }
 
     #[test]
-    fn test_u8() {
-        let v = ValueRef::from(42_u8);
-        let actual = u8::from_sql(v).unwrap();
-        assert_eq!(actual, 42_u8);
+    fn test_datetime64_as() {
+        let params = (3, Tz::UTC);
+        let v = ValueRef::DateTime64(1_546_300_800_123, &params);
+        assert_eq!(
+            DateTime64As::<3>::from_sql(v.clone()).unwrap(),
+            DateTime64As(1_546_300_800_123)
+        );
+        assert_eq!(
+            DateTime64As::<6>::from_sql(v.clone()).unwrap(),
+            DateTime64As(1_546_300_800_123_000)
+        );
+        assert!(DateTime64As::<0>::from_sql(v).is_err());
+
+        let params = (6, Tz::UTC);
+        let v = ValueRef::DateTime64(1_546_300_800_123_000, &params);
+        assert_eq!(
+            DateTime64As::<3>::from_sql(v).unwrap(),
+            DateTime64As(1_546_300_800_123)
+        );
     }
 
     #[test]
-    fn test_bad_convert() {
-        let v = ValueRef::from(42_u16);
-        match u32::from_sql(v) {
+    fn test_naive_date_time() {
+        let date = NaiveDate::from_ymd_opt(2018, 11, 10).unwrap();
+        let v = ValueRef::Date(17_845, Tz::UTC);
+        assert_eq!(NaiveDate::from_sql(v).unwrap(), date);
+
+        let v = ValueRef::DateTime(1_541_815_603, Tz::UTC);
+        let expected = date.and_hms_opt(2, 6, 43).unwrap();
+        assert_eq!(NaiveDateTime::from_sql(v).unwrap(), expected);
+
+        let v = ValueRef::DateTime(1_541_815_603, Tz::Asia__Tokyo);
+        let expected = date.and_hms_opt(11, 6, 43).unwrap();
+        assert_eq!(NaiveDateTime::from_sql(v).unwrap(), expected);
+
+        let params = (3, Tz::UTC);
+        let v = ValueRef::DateTime64(1_541_815_603_250, &params);
+        let expected = date.and_hms_milli_opt(2, 6, 43, 250).unwrap();
+        assert_eq!(NaiveDateTime::from_sql(v).unwrap(), expected);
+
+        assert!(NaiveDateTime::from_sql(ValueRef::Date(17_845, Tz::UTC)).is_err());
+        assert!(NaiveDate::from_sql(ValueRef::UInt32(17_845)).is_err());
+    }
+
+    #[test]
+    fn test_datetime_utc_and_local() {
+        let expected = Utc.with_ymd_and_hms(2018, 11, 10, 2, 6, 43).unwrap();
+
+        let v = ValueRef::DateTime(1_541_815_603, Tz::Asia__Tokyo);
+        assert_eq!(DateTime::<Utc>::from_sql(v.clone()).unwrap(), expected);
+        assert_eq!(DateTime::<Local>::from_sql(v).unwrap(), expected);
+
+        let params = (3, Tz::Asia__Tokyo);
+        let v = ValueRef::DateTime64(1_541_815_603_250, &params);
+        let actual = DateTime::<Utc>::from_sql(v.clone()).unwrap();
+        assert_eq!(actual, expected + chrono::Duration::milliseconds(250));
+        assert_eq!(actual.timestamp_subsec_millis(), 250);
+        assert_eq!(DateTime::<Local>::from_sql(v).unwrap(), actual);
+
+        let v = ValueRef::Array(
+            SqlType::DateTime(DateTimeType::DateTime64(3, Tz::UTC)).into(),
+            Arc::new(vec![ValueRef::DateTime64(1_541_815_603_250, &params)]),
+        );
+        assert_eq!(Vec::<DateTime<Utc>>::from_sql(v).unwrap(), vec![actual]);
+
+        match DateTime::<Utc>::from_sql(ValueRef::Date(17_845, Tz::UTC)) {
             Ok(_) => panic!("should fail"),
             Err(e) => assert_eq!(
-                "From SQL error: `SqlType::UInt16 cannot be cast to u32.`".to_string(),
+                "From SQL error: `SqlType::Date cannot be cast to DateTime<Utc>.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        let params = (3, Tz::UTC);
+        let v = ValueRef::DateTime64(-1_500, &params);
+        assert_eq!(UnixTimestamp::from_sql(v).unwrap(), UnixTimestamp(-2));
+
+        let v = ValueRef::Array(
+            SqlType::DateTime(DateTimeType::DateTime32).into(),
+            Arc::new(vec![
+                ValueRef::DateTime(1_546_300_800, Tz::UTC),
+                ValueRef::DateTime(1_546_300_801, Tz::Asia__Tokyo),
+            ]),
+        );
+        let actual = Vec::<UnixTimestamp>::from_sql(v).unwrap();
+        assert_eq!(
+            actual,
+            vec![UnixTimestamp(1_546_300_800), UnixTimestamp(1_546_300_801)]
+        );
+    }
+
+    #[test]
+    fn test_proto_timestamp() {
+        let params = (9, Tz::UTC);
+
+        let v = ValueRef::DateTime64(1_546_300_800_123_456_789, &params);
+        let actual = ProtoTimestamp::from_sql(v).unwrap();
+        assert_eq!(
+            actual,
+            ProtoTimestamp {
+                seconds: 1_546_300_800,
+                nanos: 123_456_789
+            }
+        );
+
+        let v = ValueRef::DateTime64(-1_500_000_000, &params);
+        let actual = ProtoTimestamp::from_sql(v).unwrap();
+        assert_eq!(
+            actual,
+            ProtoTimestamp {
+                seconds: -2,
+                nanos: 500_000_000
+            }
+        );
+    }
+
+    #[test]
+    fn test_joined() {
+        let v = ValueRef::Array(
+            SqlType::UInt32.into(),
+            Arc::new(vec![
+                ValueRef::UInt32(1),
+                ValueRef::UInt32(2),
+                ValueRef::UInt32(3),
+            ]),
+        );
+
+        let actual = Joined::<','>::from_sql(v.clone()).unwrap();
+        assert_eq!(actual, Joined("1,2,3".to_string()));
+
+        let actual = Joined::<';'>::from_sql(v).unwrap();
+        assert_eq!(actual, Joined("1;2;3".to_string()));
+    }
+
+    #[test]
+    fn test_lines_joined() {
+        let v = ValueRef::Array(
+            SqlType::String.into(),
+            Arc::new(vec![ValueRef::String(b"a"), ValueRef::String(b"b")]),
+        );
+        assert_eq!(
+            LinesJoined::from_sql(v).unwrap(),
+            LinesJoined("a\nb".to_string())
+        );
+
+        let v = ValueRef::Array(SqlType::String.into(), Arc::new(Vec::new()));
+        assert_eq!(LinesJoined::from_sql(v).unwrap().0, "");
+
+        let v = ValueRef::Array(SqlType::UInt32.into(), Arc::new(vec![ValueRef::UInt32(1)]));
+        assert!(LinesJoined::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_in_range() {
+        let actual = InRange::<1, 12>::from_sql(ValueRef::UInt8(7)).unwrap();
+        assert_eq!(actual, InRange(7));
+
+        assert!(InRange::<1, 12>::from_sql(ValueRef::Int32(13)).is_err());
+    }
+
+    #[test]
+    fn test_char() {
+        let v = ValueRef::String(b"A");
+        assert_eq!(char::from_sql(v).unwrap(), 'A');
+
+        let v = ValueRef::String("é".as_bytes());
+        assert_eq!(char::from_sql(v).unwrap(), 'é');
+
+        let mut reader = Cursor::new(b"Z\0".to_vec());
+        let column = FixedStringColumnData::load(&mut reader, 2, 1).unwrap();
+        assert_eq!(char::from_sql(column.at(0)).unwrap(), 'Z');
+        match char::from_sql(column.at(1)) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `Expected a single character, got 0.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+
+        match char::from_sql(ValueRef::String(b"")) {
+            Ok(_) => panic!("should fail"),
+            Err(Error::FromSql(FromSqlError::NotSingleChar { len })) => assert_eq!(len, 0),
+            Err(e) => panic!("unexpected error {}", e),
+        }
+
+        match char::from_sql(ValueRef::String(b"ab")) {
+            Ok(_) => panic!("should fail"),
+            Err(Error::FromSql(FromSqlError::NotSingleChar { len })) => assert_eq!(len, 2),
+            Err(e) => panic!("unexpected error {}", e),
+        }
+
+        assert!(char::from_sql(ValueRef::UInt8(65)).is_err());
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Channel {
+        Web,
+        Mobile,
+    }
+
+    impl CodeTable for Channel {
+        const CODES: &'static [(u32, Self)] = &[(1, Channel::Web), (2, Channel::Mobile)];
+    }
+
+    #[test]
+    fn test_code_mapped() {
+        let CodeMapped(channel) = CodeMapped::<Channel>::from_sql(ValueRef::UInt32(1)).unwrap();
+        assert_eq!(channel, Channel::Web);
+        let CodeMapped(channel) = CodeMapped::<Channel>::from_sql(ValueRef::UInt32(2)).unwrap();
+        assert_eq!(channel, Channel::Mobile);
+
+        match CodeMapped::<Channel>::from_sql(ValueRef::UInt32(3)) {
+            Err(Error::FromSql(FromSqlError::UnknownCode { code })) => assert_eq!(code, 3),
+            _ => panic!("should fail"),
+        }
+        assert!(CodeMapped::<Channel>::from_sql(ValueRef::UInt64(1)).is_err());
+    }
+
+    #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+    enum Severity {
+        Debug,
+        Info,
+        Warning,
+        Error,
+    }
+
+    impl CodeTable for Severity {
+        const CODES: &'static [(u32, Self)] = &[
+            (10, Severity::Error),
+            (20, Severity::Warning),
+            (30, Severity::Info),
+            (40, Severity::Debug),
+        ];
+    }
+
+    #[test]
+    fn test_ordered_category() {
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(SqlType::UInt8, Tz::Zulu, 4).unwrap();
+        for code in &[20_u8, 40, 10, 30] {
+            column.push(Value::UInt8(*code));
+        }
+
+        let mut actual: Vec<OrderedCategory<Severity>> = (0..column.len())
+            .map(|i| OrderedCategory::from_sql(column.at(i)).unwrap())
+            .collect();
+        actual.sort();
+
+        let expected = vec![
+            OrderedCategory(Severity::Debug),
+            OrderedCategory(Severity::Info),
+            OrderedCategory(Severity::Warning),
+            OrderedCategory(Severity::Error),
+        ];
+        assert_eq!(actual, expected);
+
+        match OrderedCategory::<Severity>::from_sql(ValueRef::UInt8(99)) {
+            Err(Error::FromSql(FromSqlError::UnknownCode { code })) => assert_eq!(code, 99),
+            _ => panic!("should fail"),
+        }
+        assert!(OrderedCategory::<Severity>::from_sql(ValueRef::UInt32(10)).is_err());
+    }
+
+    #[test]
+    fn test_snowflake() {
+        let v = ValueRef::UInt64(1_541_815_603_606_036_480);
+        let actual: Snowflake = Snowflake::from_sql(v).unwrap();
+        assert_eq!(
+            actual,
+            Snowflake {
+                timestamp_ms: 1_656_432_460_105,
+                worker: 378,
+                sequence: 0,
+            }
+        );
+
+        let v = ValueRef::UInt64((5 << 8) | (3 << 4) | 7);
+        let actual = Snowflake::<4, 4, 0>::from_sql(v).unwrap();
+        assert_eq!(
+            actual,
+            Snowflake {
+                timestamp_ms: 5,
+                worker: 3,
+                sequence: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_iso_week_key() {
+        // 2021-01-01 is a Friday and belongs to the last ISO week of 2020.
+        let v = ValueRef::Date(18628, Tz::UTC);
+        assert_eq!(IsoWeekKey::from_sql(v).unwrap(), IsoWeekKey(202053));
+
+        // 2024-12-30 is a Monday and starts the first ISO week of 2025.
+        let v = ValueRef::DateTime(1_735_560_000, Tz::UTC);
+        assert_eq!(IsoWeekKey::from_sql(v).unwrap(), IsoWeekKey(202501));
+
+        // 2024-12-29 23:30 UTC is already Monday in Asia/Tokyo.
+        let v = ValueRef::DateTime(1_735_515_000, Tz::Asia__Tokyo);
+        assert_eq!(IsoWeekKey::from_sql(v).unwrap(), IsoWeekKey(202501));
+    }
+
+    #[test]
+    fn test_ipv6_subnet64() {
+        let ip: Ipv6Addr = "2001:db8:85a3:1234:8a2e:370:7334:1".parse().unwrap();
+        let v = ValueRef::Ipv6(ip.octets());
+        let subnet = Ipv6Subnet64::from_sql(v).unwrap();
+        assert_eq!(
+            subnet,
+            Ipv6Subnet64([0x20, 0x01, 0x0d, 0xb8, 0x85, 0xa3, 0x12, 0x34])
+        );
+
+        let other: Ipv6Addr = "2001:db8:85a3:1234::ffff".parse().unwrap();
+        let v = ValueRef::Ipv6(other.octets());
+        assert_eq!(Ipv6Subnet64::from_sql(v).unwrap(), subnet);
+
+        let v = ValueRef::Ipv4([127, 0, 0, 1]);
+        assert!(Ipv6Subnet64::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_ip_as_integer() {
+        let v = ValueRef::Ipv4([1, 2, 3, 4]);
+        assert_eq!(Ipv4AsU32::from_sql(v).unwrap(), Ipv4AsU32(0x0102_0304));
+
+        let ip: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let v = ValueRef::Ipv6(ip.octets());
+        assert_eq!(
+            Ipv6AsU128::from_sql(v).unwrap(),
+            Ipv6AsU128(0x2001_0db8_0000_0000_0000_0000_0000_0001)
+        );
+
+        let v = ValueRef::Array(
+            SqlType::Ipv4.into(),
+            Arc::new(vec![
+                ValueRef::Ipv4([10, 0, 0, 1]),
+                ValueRef::Ipv4([255, 255, 255, 255]),
+            ]),
+        );
+        let actual = Vec::<Ipv4AsU32>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![Ipv4AsU32(0x0a00_0001), Ipv4AsU32(u32::MAX)]);
+
+        let v = ValueRef::Array(
+            SqlType::Ipv6.into(),
+            Arc::new(vec![ValueRef::Ipv6([0; 16])]),
+        );
+        let actual = Vec::<Ipv6AsU128>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![Ipv6AsU128(0)]);
+
+        assert!(Ipv4AsU32::from_sql(ValueRef::Ipv6([0; 16])).is_err());
+        assert!(Ipv6AsU128::from_sql(ValueRef::Ipv4([127, 0, 0, 1])).is_err());
+    }
+
+    #[test]
+    fn test_quarter_key() {
+        // 2023-06-15
+        let v = ValueRef::Date(19523, Tz::UTC);
+        assert_eq!(QuarterKey::from_sql(v).unwrap().0, "2023-Q2");
+
+        // 2023-03-31 23:59:59 and 2023-04-01 00:00:00 UTC.
+        let v = ValueRef::DateTime(1_680_307_199, Tz::UTC);
+        assert_eq!(QuarterKey::from_sql(v).unwrap().0, "2023-Q1");
+        let v = ValueRef::DateTime(1_680_307_200, Tz::UTC);
+        assert_eq!(QuarterKey::from_sql(v).unwrap().0, "2023-Q2");
+
+        // 2023-12-31 20:00 UTC is already 2024 in Asia/Tokyo.
+        let v = ValueRef::DateTime(1_704_052_800, Tz::Asia__Tokyo);
+        assert_eq!(QuarterKey::from_sql(v).unwrap().0, "2024-Q1");
+    }
+
+    #[test]
+    fn test_weekday() {
+        use chrono::Weekday;
+
+        // 2021-01-01 was a Friday.
+        let v = ValueRef::Date(18628, Tz::UTC);
+        let actual = Weekday::from_sql(v).unwrap();
+        assert_eq!(actual, Weekday::Fri);
+        assert_eq!(actual.num_days_from_monday(), 4);
+        assert_eq!(actual.num_days_from_sunday(), 5);
+
+        // 2024-12-29 23:30 UTC is Sunday in UTC but Monday in Asia/Tokyo.
+        let v = ValueRef::DateTime(1_735_515_000, Tz::UTC);
+        assert_eq!(Weekday::from_sql(v).unwrap(), Weekday::Sun);
+        let v = ValueRef::DateTime(1_735_515_000, Tz::Asia__Tokyo);
+        assert_eq!(Weekday::from_sql(v).unwrap(), Weekday::Mon);
+    }
+
+    #[test]
+    fn test_money() {
+        let money = |amount, scale| Money { amount, scale };
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(SqlType::Decimal(18, 2), Tz::Zulu, 2)
+                .unwrap();
+        column.push(Value::Decimal(Decimal::of(19.75_f64, 2)));
+        column.push(Value::Decimal(Decimal::of(0.25_f64, 2)));
+
+        let price = Money::from_sql(column.at(0)).unwrap();
+        assert_eq!(price, money(1975, 2));
+        let tip = Money::from_sql(column.at(1)).unwrap();
+
+        assert_eq!(price.checked_add(tip), Some(money(2000, 2)));
+        assert_eq!(tip.checked_sub(price), Some(money(-1950, 2)));
+        assert_eq!(price.checked_add(money(1, 1)), Some(money(1985, 2)));
+        assert_eq!(money(i128::MAX, 2).checked_add(tip), None);
+
+        assert!(Money::from_sql(ValueRef::Float64(19.75)).is_err());
+    }
+
+    #[test]
+    fn test_scaled_i128() {
+        let v = ValueRef::Decimal(Decimal::of(1.25_f64, 2));
+        assert_eq!(
+            ScaledI128::<4>::from_sql(v.clone()).unwrap(),
+            ScaledI128(12_500)
+        );
+        assert_eq!(
+            ScaledI128::<2>::from_sql(v.clone()).unwrap(),
+            ScaledI128(125)
+        );
+        assert!(ScaledI128::<1>::from_sql(v).is_err());
+
+        let v = ValueRef::Decimal(Decimal::of(1.5_f64, 2));
+        assert_eq!(
+            ScaledI128::<1>::from_sql(v.clone()).unwrap(),
+            ScaledI128(15)
+        );
+        assert!(ScaledI128::<0>::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_enum_label() {
+        let values = vec![("a".to_string(), 1), ("b".to_string(), 2)];
+        let mut column = <dyn ColumnData>::from_type::<BoxColumnWrapper>(
+            SqlType::Enum8(values.clone()),
+            Tz::Zulu,
+            2,
+        )
+        .unwrap();
+        column.push(Value::Enum8(values.clone(), Enum8::of(2)));
+        column.push(Value::Enum8(values.clone(), Enum8::of(1)));
+
+        assert_eq!(String::from_sql(column.at(0)).unwrap(), "b");
+        assert_eq!(String::from_sql(column.at(1)).unwrap(), "a");
+
+        match String::from_sql(ValueRef::Enum8(values, Enum8::of(3))) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `Enum value 3 has no label.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_vec_of_enum_labels() {
+        let values = vec![("a".to_string(), 1), ("b".to_string(), 2)];
+        let sql_type: &'static SqlType = SqlType::Enum8(values.clone()).into();
+        let item = |v| ValueRef::Enum8(values.clone(), Enum8::of(v));
+
+        let v = ValueRef::Array(sql_type, Arc::new(vec![item(1), item(2), item(1)]));
+        let actual = Vec::<String>::from_sql(v).unwrap();
+        assert_eq!(actual, vec!["a", "b", "a"]);
+
+        let v = ValueRef::Array(sql_type, Arc::new(vec![item(1), item(3)]));
+        assert!(Vec::<String>::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_finite() {
+        assert_eq!(
+            Finite::<f64>::from_sql(ValueRef::Float64(1.5)).unwrap(),
+            Finite(1.5)
+        );
+        assert!(Finite::<f64>::from_sql(ValueRef::Float64(f64::NAN)).is_err());
+        assert!(Finite::<f32>::from_sql(ValueRef::Float32(f32::INFINITY)).is_err());
+        assert!(Finite::<f64>::from_sql(ValueRef::Float64(f64::NEG_INFINITY)).is_err());
+    }
+
+    #[test]
+    fn test_durations() {
+        let actual = Seconds::<u32>::from_sql(ValueRef::UInt32(90)).unwrap();
+        assert_eq!(actual.duration, Duration::from_secs(90));
+
+        let actual = Millis::<i64>::from_sql(ValueRef::Int64(1_500)).unwrap();
+        assert_eq!(Duration::from(actual), Duration::from_millis(1_500));
+
+        assert!(Millis::<i64>::from_sql(ValueRef::Int64(-1)).is_err());
+        assert!(Seconds::<u32>::from_sql(ValueRef::Int64(1)).is_err());
+
+        let v = ValueRef::Array(
+            SqlType::UInt32.into(),
+            Arc::new(vec![ValueRef::UInt32(1), ValueRef::UInt32(60)]),
+        );
+        let actual: Vec<Duration> = Vec::<Seconds<u32>>::from_sql(v)
+            .unwrap()
+            .into_iter()
+            .map(Duration::from)
+            .collect();
+        assert_eq!(
+            actual,
+            vec![Duration::from_secs(1), Duration::from_secs(60)]
+        );
+
+        let v = ValueRef::Array(SqlType::Int64.into(), Arc::new(vec![ValueRef::Int64(1)]));
+        assert!(Vec::<Seconds<u32>>::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        let v = ValueRef::String(&[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(Hex::from_sql(v).unwrap(), Hex("deadbeef".to_string()));
+
+        let v = ValueRef::Array(
+            SqlType::UInt8.into(),
+            Arc::new(vec![ValueRef::UInt8(0), ValueRef::UInt8(0x0f)]),
+        );
+        assert_eq!(Hex::from_sql(v).unwrap(), Hex("000f".to_string()));
+
+        assert!(Hex::from_sql(ValueRef::UInt8(1)).is_err());
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<u8>(), "u8");
+        assert_eq!(short_type_name::<String>(), "String");
+        assert_eq!(
+            short_type_name::<Vec<Option<String>>>(),
+            "Vec<Option<String>>"
+        );
+        assert_eq!(short_type_name::<(u8, String)>(), "(u8, String)");
+    }
+
+    #[test]
+    fn test_parse_num() {
+        let actual = ParseNum::<u32>::from_sql(ValueRef::String(b"42")).unwrap();
+        assert_eq!(actual, ParseNum(42));
+
+        match ParseNum::<u32>::from_sql(ValueRef::String(b"4x2")) {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("String", "u32"))
+            }
+            _ => panic!("should fail"),
+        }
+        assert!(ParseNum::<u32>::from_sql(ValueRef::String(b" 42")).is_err());
+        assert!(ParseNum::<u32>::from_sql(ValueRef::UInt32(42)).is_err());
+    }
+
+    #[test]
+    fn test_uuid_from_string() {
+        let v = ValueRef::from("936da01f-9abd-4d9d-80c7-02af85c822a8");
+        let actual = uuid::Uuid::from_sql(v).unwrap();
+        assert_eq!(actual.to_string(), "936da01f-9abd-4d9d-80c7-02af85c822a8");
+
+        let v = ValueRef::from("936da01f-9abd-4d9d-80c7");
+        match uuid::Uuid::from_sql(v) {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("String", "Uuid"))
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[test]
+    fn test_uuid_from_bytes() {
+        let uuid = uuid::Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+
+        let mut reader = Cursor::new(uuid.as_bytes().to_vec());
+        let column = FixedStringColumnData::load(&mut reader, 1, 16).unwrap();
+        assert_eq!(uuid::Uuid::from_sql(column.at(0)).unwrap(), uuid);
+
+        let bytes = uuid.as_bytes().iter().map(|&b| b.into()).collect();
+        let v = ValueRef::Array(SqlType::UInt8.into(), Arc::new(bytes));
+        assert_eq!(uuid::Uuid::from_sql(v).unwrap(), uuid);
+
+        let v = ValueRef::Array(SqlType::UInt8.into(), Arc::new(vec![ValueRef::UInt8(1)]));
+        assert!(uuid::Uuid::from_sql(v).is_err());
+    }
+
+    #[test]
+    fn test_uuid_rejects_16_character_text() {
+        assert!(uuid::Uuid::from_sql(ValueRef::from("abcdefghijklmnop")).is_err());
+
+        let mut reader = Cursor::new(b"0123456789abcdef".to_vec());
+        let column = FixedStringColumnData::load(&mut reader, 1, 16).unwrap();
+        assert!(uuid::Uuid::from_sql(column.at(0)).is_err());
+    }
+
+    #[test]
+    fn test_uuid_as_u128_and_string() {
+        let uuid = uuid::Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+        let v = ValueRef::Uuid(*uuid.as_bytes());
+
+        assert_eq!(
+            UuidU128::from_sql(v.clone()).unwrap(),
+            UuidU128(0x936d_a01f_9abd_4d9d_80c7_02af_85c8_22a8)
+        );
+        assert_eq!(
+            String::from_sql(v).unwrap(),
+            "936da01f-9abd-4d9d-80c7-02af85c822a8"
+        );
+
+        assert!(UuidU128::from_sql(ValueRef::UInt64(1)).is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let map_type = SqlType::Map(SqlType::String.into(), SqlType::UInt32.into());
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(map_type, Tz::Zulu, 1).unwrap();
+        column.push(Value::Map(
+            SqlType::String.into(),
+            SqlType::UInt32.into(),
+            Arc::new(vec![("b".into(), 2_u32.into()), ("a".into(), 1_u32.into())]),
+        ));
+
+        let actual = HashMap::<String, u32>::from_sql(column.at(0)).unwrap();
+        let expected: HashMap<_, _> = vec![("a".to_string(), 1), ("b".to_string(), 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(actual, expected);
+
+        let actual = BTreeMap::<String, u32>::from_sql(column.at(0)).unwrap();
+        let keys: Vec<_> = actual.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+
+        assert!(HashMap::<String, i64>::from_sql(column.at(0)).is_err());
+        match HashMap::<String, u32>::from_sql(ValueRef::UInt32(1)) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `SqlType::UInt32 cannot be cast to HashMap.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_time_series_map() {
+        let datetime = SqlType::DateTime(DateTimeType::DateTime32);
+        let map_type = SqlType::Map(datetime.clone().into(), SqlType::Float64.into());
+        let mut column =
+            <dyn ColumnData>::from_type::<BoxColumnWrapper>(map_type, Tz::Zulu, 1).unwrap();
+        column.push(Value::Map(
+            datetime.into(),
+            SqlType::Float64.into(),
+            Arc::new(vec![
+                (Value::DateTime(1_600_000_120, Tz::Zulu), 3.0_f64.into()),
+                (Value::DateTime(1_600_000_000, Tz::Zulu), 1.0_f64.into()),
+                (Value::DateTime(1_600_000_060, Tz::Zulu), 2.0_f64.into()),
+            ]),
+        ));
+
+        let actual = BTreeMap::<UnixTimestamp, f64>::from_sql(column.at(0)).unwrap();
+        let series: Vec<(i64, f64)> = actual.into_iter().map(|(k, v)| (k.0, v)).collect();
+        assert_eq!(
+            series,
+            vec![
+                (1_600_000_000, 1.0),
+                (1_600_000_060, 2.0),
+                (1_600_000_120, 3.0)
+            ]
+        );
+
+        let actual = BTreeMap::<i64, f64>::from_sql(column.at(0)).unwrap();
+        let series: Vec<(i64, f64)> = actual.into_iter().collect();
+        assert_eq!(
+            series,
+            vec![
+                (1_600_000_000, 1.0),
+                (1_600_000_060, 2.0),
+                (1_600_000_120, 3.0)
+            ]
+        );
+        assert!(BTreeMap::<String, f64>::from_sql(column.at(0)).is_err());
+    }
+
+    #[test]
+    fn test_vec_of_results() {
+        let v = ValueRef::Array(
+            SqlType::UInt32.into(),
+            Arc::new(vec![
+                ValueRef::UInt32(1),
+                ValueRef::UInt16(2),
+                ValueRef::UInt32(3),
+            ]),
+        );
+
+        let actual = Vec::<Result<u32>>::from_sql(v).unwrap();
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0].as_ref().unwrap(), &1);
+        assert!(actual[1].is_err());
+        assert_eq!(actual[2].as_ref().unwrap(), &3);
+
+        match Vec::<Result<u32>>::from_sql(ValueRef::UInt32(1)) {
+            Err(Error::FromSql(FromSqlError::InvalidType { dst, .. })) => {
+                assert_eq!(dst, "Vec<Result<u32>>")
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_array() {
+        let rgb = |vs: Vec<u8>| {
+            ValueRef::Array(
+                SqlType::UInt8.into(),
+                Arc::new(vs.into_iter().map(ValueRef::UInt8).collect()),
+            )
+        };
+
+        assert_eq!(
+            <[u8; 3]>::from_sql(rgb(vec![255, 128, 0])).unwrap(),
+            [255, 128, 0]
+        );
+
+        match <[u8; 3]>::from_sql(rgb(vec![1, 2, 3, 4])) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `Expected [u8; 3], got 4 elements.`".to_string(),
+                format!("{}", e)
+            ),
+        }
+        match <[u8; 3]>::from_sql(rgb(vec![1, 2])) {
+            Err(Error::FromSql(FromSqlError::ArrayLength {
+                expected, actual, ..
+            })) => assert_eq!((expected, actual), (3, 2)),
+            _ => panic!("should fail"),
+        }
+
+        let words = ValueRef::Array(
+            SqlType::String.into(),
+            Arc::new(vec![ValueRef::String(b"a"), ValueRef::String(b"b")]),
+        );
+        assert!(matches!(
+            <[u8; 3]>::from_sql(words.clone()),
+            Err(Error::FromSql(FromSqlError::ArrayLength { actual: 2, .. }))
+        ));
+        match <[String; 3]>::from_sql(words) {
+            Ok(_) => panic!("should fail"),
+            Err(e) => assert_eq!(
+                "From SQL error: `Expected [String; 3], got 2 elements.`".to_string(),
                 format!("{}", e)
             ),
         }
+
+        match <[u8; 3]>::from_sql(ValueRef::UInt8(1)) {
+            Err(Error::FromSql(FromSqlError::InvalidType { dst, .. })) => {
+                assert_eq!(dst, "[u8; 3]")
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[test]
+    fn test_nested_vec() {
+        let inner_type: &'static SqlType = SqlType::Int32.into();
+        let outer_type: &'static SqlType = SqlType::Array(inner_type).into();
+        let v = ValueRef::Array(
+            outer_type,
+            Arc::new(vec![
+                ValueRef::Array(inner_type, Arc::new(vec![1_i32.into(), 2_i32.into()])),
+                ValueRef::Array(inner_type, Arc::new(Vec::new())),
+                ValueRef::Array(inner_type, Arc::new(vec![3_i32.into()])),
+            ]),
+        );
+        let actual = Vec::<Vec<i32>>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![vec![1, 2], vec![], vec![3]]);
+
+        let v = ValueRef::Array(outer_type, Arc::new(Vec::new()));
+        assert!(Vec::<Vec<i32>>::from_sql(v).unwrap().is_empty());
+
+        let paths_type: &'static SqlType = SqlType::Array(SqlType::String.into()).into();
+        let v = ValueRef::Array(
+            paths_type,
+            Arc::new(vec![ValueRef::Array(
+                SqlType::String.into(),
+                Arc::new(vec!["/".into(), "/about".into()]),
+            )]),
+        );
+        let actual = Vec::<Vec<String>>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![vec!["/".to_string(), "/about".to_string()]]);
+
+        let v = ValueRef::Array(inner_type, Arc::new(vec![1_i32.into()]));
+        match Vec::<Vec<i32>>::from_sql(v) {
+            Err(Error::FromSql(FromSqlError::InvalidType { dst, .. })) => {
+                assert_eq!(dst, "Vec<Vec<i32>>")
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[test]
+    fn test_vec_of_byte_vecs() {
+        let bytes_type: &'static SqlType = SqlType::UInt8.into();
+        let v = ValueRef::Array(
+            SqlType::Array(bytes_type).into(),
+            Arc::new(vec![
+                ValueRef::Array(bytes_type, Arc::new(vec![0_u8.into(), 255_u8.into()])),
+                ValueRef::Array(bytes_type, Arc::new(vec![7_u8.into()])),
+            ]),
+        );
+        let actual = Vec::<Vec<u8>>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![vec![0, 255], vec![7]]);
+
+        let v = ValueRef::Array(
+            SqlType::String.into(),
+            Arc::new(vec![ValueRef::String(&[0, 159]), ValueRef::String(b"ab")]),
+        );
+        let actual = Vec::<Vec<u8>>::from_sql(v).unwrap();
+        assert_eq!(actual, vec![vec![0, 159], b"ab".to_vec()]);
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff_timestamp() {
+        let params = (6, Tz::UTC);
+        let v = ValueRef::DateTime64(1_546_300_800_123_456, &params);
+        let actual = jiff::Timestamp::from_sql(v).unwrap();
+        assert_eq!(actual.as_second(), 1_546_300_800);
+        assert_eq!(actual.subsec_microsecond(), 123_456);
+
+        let params = (10, Tz::UTC);
+        let v = ValueRef::DateTime64(1, &params);
+        assert!(matches!(
+            jiff::Timestamp::from_sql(v),
+            Err(Error::FromSql(FromSqlError::OutOfRange))
+        ));
+
+        let params = (0, Tz::UTC);
+        let v = ValueRef::DateTime64(i64::MAX, &params);
+        assert!(matches!(
+            jiff::Timestamp::from_sql(v),
+            Err(Error::FromSql(FromSqlError::OutOfRange))
+        ));
+
+        match jiff::Timestamp::from_sql(ValueRef::UInt32(1)) {
+            Err(Error::FromSql(FromSqlError::InvalidType { dst, .. })) => {
+                assert_eq!(dst, "jiff::Timestamp")
+            }
+            _ => panic!("should fail"),
+        }
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_fixed() {
+        use fixed::types::{I64F64, U126F2, U64F64};
+
+        use crate::types::decimal::NoBits;
+
+        let v = ValueRef::Decimal(Decimal {
+            underlying: 1225,
+            precision: 10,
+            scale: 2,
+            nobits: NoBits::N64,
+        });
+        assert_eq!(
+            I64F64::from_sql(v.clone()).unwrap(),
+            I64F64::from_num(12.25)
+        );
+        assert_eq!(U64F64::from_sql(v).unwrap(), U64F64::from_num(12.25));
+
+        let v = ValueRef::Decimal(Decimal::of(-0.5_f64, 2));
+        assert_eq!(I64F64::from_sql(v.clone()).unwrap(), I64F64::from_num(-0.5));
+        assert!(U64F64::from_sql(v).is_err());
+
+        // 0.1 isn't representable with two fractional bits; the nearest value is 0.0,
+        // while 0.13 rounds up to 0.25.
+        let v = ValueRef::Decimal(Decimal::of(0.1_f64, 2));
+        assert_eq!(U126F2::from_sql(v).unwrap(), U126F2::from_num(0));
+        let v = ValueRef::Decimal(Decimal::of(0.13_f64, 2));
+        assert_eq!(U126F2::from_sql(v).unwrap(), U126F2::from_num(0.25));
     }
 }
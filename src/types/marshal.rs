@@ -74,6 +74,18 @@ impl Marshal for i64 {
     }
 }
 
+impl Marshal for u128 {
+    fn marshal(&self, scratch: &mut [u8]) {
+        scratch[..16].copy_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl Marshal for i128 {
+    fn marshal(&self, scratch: &mut [u8]) {
+        scratch[..16].copy_from_slice(&self.to_le_bytes());
+    }
+}
+
 impl Marshal for f32 {
     fn marshal(&self, scratch: &mut [u8]) {
         let bits = self.to_bits();
@@ -168,6 +180,16 @@ mod test {
         test_some::<i64>()
     }
 
+    #[test]
+    fn test_u128() {
+        test_some::<u128>()
+    }
+
+    #[test]
+    fn test_i128() {
+        test_some::<i128>()
+    }
+
     #[test]
     fn test_f32() {
         test_some::<f32>()
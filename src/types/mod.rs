@@ -11,12 +11,17 @@ use crate::errors::ServerError;
 pub use self::{
     block::{Block, RCons, RNil, Row, RowBuilder, Rows},
     column::{Column, ColumnType, Simple, Complex},
-    decimal::Decimal,
+    decimal::{Decimal, RoundingMode},
     enums::{Enum16, Enum8},
-    from_sql::FromSql,
+    from_sql::{
+        CodeMapped, CodeTable, DateTime64As, Finite, FromSql, Hex, InRange, Ipv4AsU32, Ipv6AsU128,
+        Ipv6Subnet64, IsoWeekKey, Joined, LinesJoined, Millis, Money, OrderedCategory, ParseNum,
+        ProtoTimestamp, QuarterKey, ScaledI128, Seconds, Snowflake, UnixTimestamp, UuidU128, Widen,
+    },
     options::Options,
     query::Query,
     query_result::QueryResult,
+    to_sql::ToSql,
     value::Value,
 };
 
@@ -36,6 +41,7 @@ mod stat_buffer;
 mod unmarshal;
 
 mod from_sql;
+mod to_sql;
 mod value;
 mod value_ref;
 
@@ -184,16 +190,21 @@ has_sql_type! {
     u16: SqlType::UInt16,
     u32: SqlType::UInt32,
     u64: SqlType::UInt64,
+    u128: SqlType::UInt128,
     i8: SqlType::Int8,
     i16: SqlType::Int16,
     i32: SqlType::Int32,
     i64: SqlType::Int64,
+    i128: SqlType::Int128,
     &str: SqlType::String,
     String: SqlType::String,
     f32: SqlType::Float32,
     f64: SqlType::Float64,
     Date<Tz>: SqlType::Date,
-    DateTime<Tz>: SqlType::DateTime(DateTimeType::DateTime32)
+    DateTime<Tz>: SqlType::DateTime(DateTimeType::DateTime32),
+    std::net::Ipv4Addr: SqlType::Ipv4,
+    std::net::Ipv6Addr: SqlType::Ipv6,
+    uuid::Uuid: SqlType::Uuid
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -209,10 +220,12 @@ pub enum SqlType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
+    Int128,
     String,
     FixedString(usize),
     Float32,
@@ -224,6 +237,7 @@ pub enum SqlType {
     Uuid,
     Nullable(&'static SqlType),
     Array(&'static SqlType),
+    Map(&'static SqlType, &'static SqlType),
     Decimal(u8, u8),
     Enum8(Vec<(String, i8)>),
     Enum16(Vec<(String, i16)>),
@@ -240,10 +254,12 @@ impl From<SqlType> for &'static SqlType {
             SqlType::UInt16 => &SqlType::UInt16,
             SqlType::UInt32 => &SqlType::UInt32,
             SqlType::UInt64 => &SqlType::UInt64,
+            SqlType::UInt128 => &SqlType::UInt128,
             SqlType::Int8 => &SqlType::Int8,
             SqlType::Int16 => &SqlType::Int16,
             SqlType::Int32 => &SqlType::Int32,
             SqlType::Int64 => &SqlType::Int64,
+            SqlType::Int128 => &SqlType::Int128,
             SqlType::String => &SqlType::String,
             SqlType::Float32 => &SqlType::Float32,
             SqlType::Float64 => &SqlType::Float64,
@@ -269,16 +285,46 @@ impl SqlType {
         }
     }
 
+    /// Whether values of this type can be cast to `target` at all: both must be numeric,
+    /// each optionally `Nullable`. Whether a particular value fits is checked on cast.
+    pub(crate) fn is_compatible_with(&self, target: &SqlType) -> bool {
+        fn is_numeric(sql_type: &SqlType) -> bool {
+            matches!(
+                sql_type,
+                SqlType::UInt8
+                    | SqlType::UInt16
+                    | SqlType::UInt32
+                    | SqlType::UInt64
+                    | SqlType::UInt128
+                    | SqlType::Int8
+                    | SqlType::Int16
+                    | SqlType::Int32
+                    | SqlType::Int64
+                    | SqlType::Int128
+                    | SqlType::Float32
+                    | SqlType::Float64
+            )
+        }
+
+        match (self, target) {
+            (SqlType::Nullable(inner), _) => inner.is_compatible_with(target),
+            (_, SqlType::Nullable(inner)) => self.is_compatible_with(inner),
+            _ => is_numeric(self) && is_numeric(target),
+        }
+    }
+
     pub fn to_string(&self) -> Cow<'static, str> {
         match self.clone() {
             SqlType::UInt8 => "UInt8".into(),
             SqlType::UInt16 => "UInt16".into(),
             SqlType::UInt32 => "UInt32".into(),
             SqlType::UInt64 => "UInt64".into(),
+            SqlType::UInt128 => "UInt128".into(),
             SqlType::Int8 => "Int8".into(),
             SqlType::Int16 => "Int16".into(),
             SqlType::Int32 => "Int32".into(),
             SqlType::Int64 => "Int64".into(),
+            SqlType::Int128 => "Int128".into(),
             SqlType::String => "String".into(),
             SqlType::FixedString(str_len) => format!("FixedString({})", str_len).into(),
             SqlType::Float32 => "Float32".into(),
@@ -291,6 +337,7 @@ impl SqlType {
             SqlType::Uuid => "UUID".into(),
             SqlType::Nullable(nested) => format!("Nullable({})", &nested).into(),
             SqlType::Array(nested) => format!("Array({})", &nested).into(),
+            SqlType::Map(key, value) => format!("Map({}, {})", &key, &value).into(),
             SqlType::Decimal(precision, scale) => {
                 format!("Decimal({}, {})", precision, scale).into()
             }
@@ -102,6 +102,30 @@ impl StatBuffer for i64 {
     }
 }
 
+impl StatBuffer for u128 {
+    type Buffer = [u8; 16];
+
+    fn buffer() -> Self::Buffer {
+        [0; 16]
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::UInt128
+    }
+}
+
+impl StatBuffer for i128 {
+    type Buffer = [u8; 16];
+
+    fn buffer() -> Self::Buffer {
+        [0; 16]
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::Int128
+    }
+}
+
 impl StatBuffer for f32 {
     type Buffer = [u8; 4];
 
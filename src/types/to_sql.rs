@@ -0,0 +1,186 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use chrono::prelude::*;
+use chrono_tz::Tz;
+
+use crate::types::{
+    column::Either,
+    value::{AppDate, AppDateTime},
+    Decimal, HasSqlType, SqlType, Value,
+};
+
+/// Conversion of a Rust value into a [`Value`](enum.Value.html), the inverse of
+/// [`FromSql`](trait.FromSql.html).
+pub trait ToSql {
+    fn to_sql(self) -> Value;
+}
+
+macro_rules! to_sql_impl {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl ToSql for $t {
+                fn to_sql(self) -> Value {
+                    Value::from(self)
+                }
+            }
+        )*
+    };
+}
+
+to_sql_impl! {
+    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, i128,
+    f32, f64,
+    String, Decimal, AppDate, AppDateTime
+}
+
+impl ToSql for &str {
+    fn to_sql(self) -> Value {
+        Value::from(self)
+    }
+}
+
+impl ToSql for bool {
+    fn to_sql(self) -> Value {
+        Value::UInt8(self as u8)
+    }
+}
+
+impl ToSql for Ipv4Addr {
+    fn to_sql(self) -> Value {
+        Value::Ipv4(self.octets())
+    }
+}
+
+impl ToSql for Ipv6Addr {
+    fn to_sql(self) -> Value {
+        Value::Ipv6(self.octets())
+    }
+}
+
+impl ToSql for uuid::Uuid {
+    fn to_sql(self) -> Value {
+        Value::Uuid(*self.as_bytes())
+    }
+}
+
+/// Written as a `Date` in UTC.
+impl ToSql for NaiveDate {
+    fn to_sql(self) -> Value {
+        const UNIX_EPOCH_DAY: i64 = 719_163;
+        let days = i64::from(self.num_days_from_ce()) - UNIX_EPOCH_DAY;
+        Value::Date(days as u16, Tz::UTC)
+    }
+}
+
+/// Written as a `DateTime` in UTC.
+impl ToSql for NaiveDateTime {
+    fn to_sql(self) -> Value {
+        Value::DateTime(self.and_utc().timestamp() as u32, Tz::UTC)
+    }
+}
+
+/// `None` becomes a `NULL` of `T`'s SQL type.
+impl<T> ToSql for Option<T>
+where
+    T: ToSql + HasSqlType,
+{
+    fn to_sql(self) -> Value {
+        match self {
+            None => Value::Nullable(Either::Left(T::get_sql_type().into())),
+            Some(inner) => Value::Nullable(Either::Right(Box::new(inner.to_sql()))),
+        }
+    }
+}
+
+/// Written as an `Array` of `T`'s SQL type.
+impl<T> ToSql for Vec<T>
+where
+    T: ToSql + HasSqlType,
+{
+    fn to_sql(self) -> Value {
+        let sql_type: SqlType = T::get_sql_type();
+        let values: Vec<Value> = self.into_iter().map(ToSql::to_sql).collect();
+        Value::Array(sql_type.into(), values.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fmt::Debug,
+        net::{Ipv4Addr, Ipv6Addr},
+    };
+
+    use chrono::prelude::*;
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::types::{FromSql, ValueRef};
+
+    fn round_trip<T>(source: T)
+    where
+        T: ToSql + for<'a> FromSql<'a> + Clone + Debug + PartialEq,
+    {
+        let value = source.clone().to_sql();
+        let actual = T::from_sql(ValueRef::from(&value)).unwrap();
+        assert_eq!(actual, source);
+    }
+
+    #[test]
+    fn test_round_trip_scalars() {
+        round_trip(42_u8);
+        round_trip(42_u16);
+        round_trip(42_u32);
+        round_trip(42_u64);
+        round_trip(u128::MAX);
+        round_trip(-42_i8);
+        round_trip(-42_i16);
+        round_trip(-42_i32);
+        round_trip(-42_i64);
+        round_trip(i128::MIN);
+        round_trip(0.5_f32);
+        round_trip(-0.25_f64);
+        round_trip(true);
+        round_trip("hello".to_string());
+        round_trip(Decimal::of(1.5_f64, 2));
+
+        round_trip(Ipv4Addr::new(192, 168, 0, 1));
+        round_trip("2001:db8::1".parse::<Ipv6Addr>().unwrap());
+        round_trip(uuid::Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap());
+
+        round_trip(NaiveDate::from_ymd_opt(2021, 3, 14).unwrap());
+        round_trip(
+            NaiveDate::from_ymd_opt(2021, 3, 14)
+                .unwrap()
+                .and_hms_opt(15, 9, 26)
+                .unwrap(),
+        );
+        round_trip(Tz::Zulu.with_ymd_and_hms(2021, 3, 14, 15, 9, 26).unwrap());
+    }
+
+    #[test]
+    fn test_round_trip_str() {
+        let value = "hello".to_sql();
+        assert_eq!(<&str>::from_sql(ValueRef::from(&value)).unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_round_trip_option() {
+        round_trip(Some(7_u32));
+        round_trip(None::<u32>);
+
+        let value = None::<String>.to_sql();
+        assert_eq!(SqlType::from(value).to_string(), "Nullable(String)");
+    }
+
+    #[test]
+    fn test_round_trip_vec() {
+        round_trip(vec![1_i32, 2, 3]);
+        round_trip(vec!["a".to_string(), "b".to_string()]);
+        round_trip(Vec::<u64>::new());
+
+        let value = Vec::<f64>::new().to_sql();
+        assert_eq!(SqlType::from(value).to_string(), "Array(Float64)");
+    }
+}
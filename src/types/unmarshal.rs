@@ -70,6 +70,22 @@ impl Unmarshal<i64> for i64 {
     }
 }
 
+impl Unmarshal<u128> for u128 {
+    fn unmarshal(scratch: &[u8]) -> Self {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&scratch[..16]);
+        Self::from_le_bytes(bytes)
+    }
+}
+
+impl Unmarshal<i128> for i128 {
+    fn unmarshal(scratch: &[u8]) -> Self {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&scratch[..16]);
+        Self::from_le_bytes(bytes)
+    }
+}
+
 impl Unmarshal<f32> for f32 {
     fn unmarshal(scratch: &[u8]) -> Self {
         let bits = u32::from(scratch[0])
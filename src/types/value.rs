@@ -1,5 +1,7 @@
 use std::{
-    convert, fmt, mem,
+    convert, fmt,
+    hash::{Hash, Hasher},
+    mem,
     net::{Ipv4Addr, Ipv6Addr},
     str,
     sync::Arc,
@@ -26,10 +28,12 @@ pub enum Value {
     UInt16(u16),
     UInt32(u32),
     UInt64(u64),
+    UInt128(u128),
     Int8(i8),
     Int16(i16),
     Int32(i32),
     Int64(i64),
+    Int128(i128),
     String(Arc<Vec<u8>>),
     Float32(f32),
     Float64(f64),
@@ -41,6 +45,7 @@ pub enum Value {
     Uuid([u8; 16]),
     Nullable(Either<&'static SqlType, Box<Value>>),
     Array(&'static SqlType, Arc<Vec<Value>>),
+    Map(&'static SqlType, &'static SqlType, Arc<Vec<(Value, Value)>>),
     Decimal(Decimal),
     Enum8(Vec<(String, i8)>, Enum8),
     Enum16(Vec<(String, i16)>, Enum16),
@@ -53,26 +58,36 @@ impl PartialEq for Value {
             (Value::UInt16(a), Value::UInt16(b)) => *a == *b,
             (Value::UInt32(a), Value::UInt32(b)) => *a == *b,
             (Value::UInt64(a), Value::UInt64(b)) => *a == *b,
+            (Value::UInt128(a), Value::UInt128(b)) => *a == *b,
             (Value::Int8(a), Value::Int8(b)) => *a == *b,
             (Value::Int16(a), Value::Int16(b)) => *a == *b,
             (Value::Int32(a), Value::Int32(b)) => *a == *b,
             (Value::Int64(a), Value::Int64(b)) => *a == *b,
+            (Value::Int128(a), Value::Int128(b)) => *a == *b,
             (Value::String(a), Value::String(b)) => *a == *b,
-            (Value::Float32(a), Value::Float32(b)) => *a == *b,
-            (Value::Float64(a), Value::Float64(b)) => *a == *b,
-            (Value::Date(a, tz_a), Value::Date(b, tz_b)) => {
-                let time_a = tz_a.timestamp(i64::from(*a) * 24 * 3600, 0);
-                let time_b = tz_b.timestamp(i64::from(*b) * 24 * 3600, 0);
-                time_a.date() == time_b.date()
+            (Value::Float32(a), Value::Float32(b)) => {
+                float_bits(f64::from(*a)) == float_bits(f64::from(*b))
             }
+            (Value::Float64(a), Value::Float64(b)) => float_bits(*a) == float_bits(*b),
+            (Value::Date(a, _), Value::Date(b, _)) => *a == *b,
             (Value::DateTime(a, tz_a), Value::DateTime(b, tz_b)) => {
                 let time_a = tz_a.timestamp(i64::from(*a), 0);
                 let time_b = tz_b.timestamp(i64::from(*b), 0);
                 time_a == time_b
             }
+            (Value::DateTime64(a, (p_a, _)), Value::DateTime64(b, (p_b, _))) => {
+                datetime64_nanos(*a, *p_a) == datetime64_nanos(*b, *p_b)
+            }
+            (Value::Ipv4(a), Value::Ipv4(b)) => *a == *b,
+            (Value::Ipv6(a), Value::Ipv6(b)) => *a == *b,
+            (Value::Uuid(a), Value::Uuid(b)) => *a == *b,
             (Value::Nullable(a), Value::Nullable(b)) => *a == *b,
             (Value::Array(ta, a), Value::Array(tb, b)) => *ta == *tb && *a == *b,
+            (Value::Map(ka, va, a), Value::Map(kb, vb, b)) => *ka == *kb && *va == *vb && *a == *b,
             (Value::Decimal(a), Value::Decimal(b)) => *a == *b,
+            (Value::Enum8(values_a, val_a), Value::Enum8(values_b, val_b)) => {
+                *values_a == *values_b && *val_a == *val_b
+            }
             (Value::Enum16(values_a, val_a), Value::Enum16(values_b, val_b)) => {
                 *values_a == *values_b && *val_a == *val_b
             }
@@ -81,6 +96,58 @@ impl PartialEq for Value {
     }
 }
 
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Value::UInt8(v) => v.hash(state),
+            Value::UInt16(v) => v.hash(state),
+            Value::UInt32(v) => v.hash(state),
+            Value::UInt64(v) => v.hash(state),
+            Value::UInt128(v) => v.hash(state),
+            Value::Int8(v) => v.hash(state),
+            Value::Int16(v) => v.hash(state),
+            Value::Int32(v) => v.hash(state),
+            Value::Int64(v) => v.hash(state),
+            Value::Int128(v) => v.hash(state),
+            Value::String(v) => v.hash(state),
+            Value::Float32(v) => float_bits(f64::from(*v)).hash(state),
+            Value::Float64(v) => float_bits(*v).hash(state),
+            Value::Date(v, _) => v.hash(state),
+            Value::DateTime(v, _) => v.hash(state),
+            Value::DateTime64(v, (precision, _)) => datetime64_nanos(*v, *precision).hash(state),
+            Value::Ipv4(v) => v.hash(state),
+            Value::Ipv6(v) => v.hash(state),
+            Value::Uuid(v) => v.hash(state),
+            Value::Nullable(Either::Left(_)) => {}
+            Value::Nullable(Either::Right(v)) => v.hash(state),
+            Value::Array(_, vs) => vs.hash(state),
+            Value::Map(_, _, entries) => entries.hash(state),
+            Value::Decimal(v) => v.hash(state),
+            Value::Enum8(_, v) => v.internal().hash(state),
+            Value::Enum16(_, v) => v.internal().hash(state),
+        }
+    }
+}
+
+/// Bits of a float for `Eq`/`Hash`: `0.0` and `-0.0` are the same, and so are all NaNs.
+pub(crate) fn float_bits(v: f64) -> u64 {
+    if v == 0.0 {
+        0
+    } else if v.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        v.to_bits()
+    }
+}
+
+/// `DateTime64` ticks as nanoseconds, so values of different precision compare alike.
+pub(crate) fn datetime64_nanos(v: i64, precision: u32) -> i128 {
+    i128::from(v) * 10_i128.pow(9_u32.saturating_sub(precision))
+}
+
 impl Value {
     pub(crate) fn default(sql_type: SqlType) -> Value {
         match sql_type {
@@ -88,10 +155,12 @@ impl Value {
             SqlType::UInt16 => Value::UInt16(0),
             SqlType::UInt32 => Value::UInt32(0),
             SqlType::UInt64 => Value::UInt64(0),
+            SqlType::UInt128 => Value::UInt128(0),
             SqlType::Int8 => Value::Int8(0),
             SqlType::Int16 => Value::Int16(0),
             SqlType::Int32 => Value::Int32(0),
             SqlType::Int64 => Value::Int64(0),
+            SqlType::Int128 => Value::Int128(0),
             SqlType::String => Value::String(Arc::new(Vec::default())),
             SqlType::FixedString(str_len) => Value::String(Arc::new(vec![0_u8; str_len])),
             SqlType::Float32 => Value::Float32(0.0),
@@ -103,6 +172,7 @@ impl Value {
             SqlType::DateTime(_) => 0_u32.to_date(Tz::Zulu).into(),
             SqlType::Nullable(inner) => Value::Nullable(Either::Left(inner)),
             SqlType::Array(inner) => Value::Array(inner, Arc::new(Vec::default())),
+            SqlType::Map(key, value) => Value::Map(key, value, Arc::new(Vec::default())),
             SqlType::Decimal(precision, scale) => Value::Decimal(Decimal {
                 underlying: 0,
                 precision,
@@ -125,10 +195,12 @@ impl fmt::Display for Value {
             Value::UInt16(ref v) => fmt::Display::fmt(v, f),
             Value::UInt32(ref v) => fmt::Display::fmt(v, f),
             Value::UInt64(ref v) => fmt::Display::fmt(v, f),
+            Value::UInt128(ref v) => fmt::Display::fmt(v, f),
             Value::Int8(ref v) => fmt::Display::fmt(v, f),
             Value::Int16(ref v) => fmt::Display::fmt(v, f),
             Value::Int32(ref v) => fmt::Display::fmt(v, f),
             Value::Int64(ref v) => fmt::Display::fmt(v, f),
+            Value::Int128(ref v) => fmt::Display::fmt(v, f),
             Value::String(ref v) => match str::from_utf8(v) {
                 Ok(s) => fmt::Display::fmt(s, f),
                 Err(_) => write!(f, "{:?}", v),
@@ -166,6 +238,11 @@ impl fmt::Display for Value {
                 let cells: Vec<String> = vs.iter().map(|v| format!("{}", v)).collect();
                 write!(f, "[{}]", cells.join(", "))
             }
+            Value::Map(_, _, entries) => {
+                let cells: Vec<String> =
+                    entries.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{{{}}}", cells.join(", "))
+            }
             Value::Decimal(v) => fmt::Display::fmt(v, f),
             Value::Ipv4(v) => {
                 write!(f, "{}", Ipv4Addr::from(*v))
@@ -192,10 +269,12 @@ impl convert::From<Value> for SqlType {
             Value::UInt16(_) => SqlType::UInt16,
             Value::UInt32(_) => SqlType::UInt32,
             Value::UInt64(_) => SqlType::UInt64,
+            Value::UInt128(_) => SqlType::UInt128,
             Value::Int8(_) => SqlType::Int8,
             Value::Int16(_) => SqlType::Int16,
             Value::Int32(_) => SqlType::Int32,
             Value::Int64(_) => SqlType::Int64,
+            Value::Int128(_) => SqlType::Int128,
             Value::String(_) => SqlType::String,
             Value::Float32(_) => SqlType::Float32,
             Value::Float64(_) => SqlType::Float64,
@@ -209,6 +288,7 @@ impl convert::From<Value> for SqlType {
                 }
             },
             Value::Array(t, _) => SqlType::Array(t),
+            Value::Map(key, value, _) => SqlType::Map(key, value),
             Value::Decimal(v) => SqlType::Decimal(v.precision, v.scale),
             Value::Ipv4(_) => SqlType::Ipv4,
             Value::Ipv6(_) => SqlType::Ipv6,
@@ -298,11 +378,13 @@ value_from! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     i8: Int8,
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
 
     f32: Float32,
     f64: Float64,
@@ -391,16 +473,20 @@ from_value! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
     i8: Int8,
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
     f32: Float32,
     f64: Float64
 }
 
 #[cfg(test)]
 mod test {
+    use std::collections::{hash_map::DefaultHasher, HashSet};
+
     use super::*;
     use chrono_tz::Tz::{self, UTC};
     use std::fmt;
@@ -607,4 +693,56 @@ mod test {
             Value::Nullable(Either::Right(Value::DateTime(1_546_300_800, Tz::UTC).into()))
         );
     }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        set.insert(Value::UInt32(1));
+        set.insert(Value::UInt32(1));
+        set.insert(Value::UInt32(2));
+        set.insert(Value::from("a"));
+        set.insert(Value::from("a"));
+        set.insert(Value::Uuid([1; 16]));
+        set.insert(Value::Uuid([1; 16]));
+        set.insert(Value::Decimal(Decimal::of(1.5_f64, 1)));
+        set.insert(Value::Decimal(Decimal::of(1.5_f64, 3)));
+        set.insert(Value::Nullable(Either::Left(SqlType::UInt32.into())));
+        set.insert(Value::Nullable(Either::Left(SqlType::UInt32.into())));
+        assert_eq!(set.len(), 6);
+    }
+
+    #[test]
+    fn test_float_eq_and_hash() {
+        fn hash_of(value: &Value) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let nan = Value::Float64(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(hash_of(&nan), hash_of(&nan.clone()));
+        assert_eq!(nan, Value::Float64(-f64::NAN));
+        assert_eq!(Value::Float32(f32::NAN), Value::Float32(f32::NAN));
+
+        let (zero, neg_zero) = (Value::Float64(0.0), Value::Float64(-0.0));
+        assert_eq!(zero, neg_zero);
+        assert_eq!(hash_of(&zero), hash_of(&neg_zero));
+        assert_ne!(zero, Value::Float64(1.0));
+    }
+
+    #[test]
+    fn test_date_eq_and_hash_ignore_tz() {
+        fn hash_of(value: &Value) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let utc = Value::Date(18628, Tz::UTC);
+        let new_york = Value::Date(18628, Tz::America__New_York);
+        assert_eq!(utc, new_york);
+        assert_eq!(hash_of(&utc), hash_of(&new_york));
+        assert_ne!(utc, Value::Date(18629, Tz::UTC));
+    }
 }
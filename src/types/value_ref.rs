@@ -1,4 +1,12 @@
-use std::{convert, fmt, str, sync::Arc, net::{Ipv4Addr, Ipv6Addr}};
+use std::{
+    convert::{self, TryFrom},
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+    mem,
+    net::{Ipv4Addr, Ipv6Addr},
+    str,
+    sync::Arc,
+};
 
 use chrono::prelude::*;
 use chrono_tz::Tz;
@@ -8,8 +16,8 @@ use crate::{
     types::{
         Enum8, Enum16,
         column::{Either, datetime64::to_datetime},
-        decimal::Decimal,
-        value::{AppDate, AppDateTime},
+        decimal::{Decimal, RoundingMode},
+        value::{datetime64_nanos, float_bits, AppDate, AppDateTime},
         SqlType, DateTimeType, Value,
     },
 };
@@ -22,10 +30,12 @@ pub enum ValueRef<'a> {
     UInt16(u16),
     UInt32(u32),
     UInt64(u64),
+    UInt128(u128),
     Int8(i8),
     Int16(i16),
     Int32(i32),
     Int64(i64),
+    Int128(i128),
     String(&'a [u8]),
     Float32(f32),
     Float64(f64),
@@ -34,6 +44,11 @@ pub enum ValueRef<'a> {
     DateTime64(i64, &'a (u32, Tz)),
     Nullable(Either<&'static SqlType, Box<ValueRef<'a>>>),
     Array(&'static SqlType, Arc<Vec<ValueRef<'a>>>),
+    Map(
+        &'static SqlType,
+        &'static SqlType,
+        Arc<Vec<(ValueRef<'a>, ValueRef<'a>)>>,
+    ),
     Decimal(Decimal),
     Ipv4([u8; 4]),
     Ipv6([u8; 16]),
@@ -49,18 +64,18 @@ impl<'a> PartialEq for ValueRef<'a> {
             (ValueRef::UInt16(a), ValueRef::UInt16(b)) => *a == *b,
             (ValueRef::UInt32(a), ValueRef::UInt32(b)) => *a == *b,
             (ValueRef::UInt64(a), ValueRef::UInt64(b)) => *a == *b,
+            (ValueRef::UInt128(a), ValueRef::UInt128(b)) => *a == *b,
             (ValueRef::Int8(a), ValueRef::Int8(b)) => *a == *b,
             (ValueRef::Int16(a), ValueRef::Int16(b)) => *a == *b,
             (ValueRef::Int32(a), ValueRef::Int32(b)) => *a == *b,
             (ValueRef::Int64(a), ValueRef::Int64(b)) => *a == *b,
+            (ValueRef::Int128(a), ValueRef::Int128(b)) => *a == *b,
             (ValueRef::String(a), ValueRef::String(b)) => *a == *b,
-            (ValueRef::Float32(a), ValueRef::Float32(b)) => *a == *b,
-            (ValueRef::Float64(a), ValueRef::Float64(b)) => *a == *b,
-            (ValueRef::Date(a, tz_a), ValueRef::Date(b, tz_b)) => {
-                let time_a = tz_a.timestamp(i64::from(*a) * 24 * 3600, 0);
-                let time_b = tz_b.timestamp(i64::from(*b) * 24 * 3600, 0);
-                time_a.date() == time_b.date()
+            (ValueRef::Float32(a), ValueRef::Float32(b)) => {
+                float_bits(f64::from(*a)) == float_bits(f64::from(*b))
             }
+            (ValueRef::Float64(a), ValueRef::Float64(b)) => float_bits(*a) == float_bits(*b),
+            (ValueRef::Date(a, _), ValueRef::Date(b, _)) => *a == *b,
             (ValueRef::DateTime(a, tz_a), ValueRef::DateTime(b, tz_b)) => {
                 let time_a = tz_a.timestamp(i64::from(*a), 0);
                 let time_b = tz_b.timestamp(i64::from(*b), 0);
@@ -68,23 +83,62 @@ impl<'a> PartialEq for ValueRef<'a> {
             }
             (ValueRef::Nullable(a), ValueRef::Nullable(b)) => *a == *b,
             (ValueRef::Array(ta, a), ValueRef::Array(tb, b)) => *ta == *tb && *a == *b,
+            (ValueRef::Map(ka, va, a), ValueRef::Map(kb, vb, b)) => {
+                *ka == *kb && *va == *vb && *a == *b
+            }
             (ValueRef::Decimal(a), ValueRef::Decimal(b)) => *a == *b,
             (ValueRef::Enum8(a0, a1), ValueRef::Enum8(b0, b1)) => *a1 == *b1 && *a0 == *b0,
             (ValueRef::Enum16(a0, a1), ValueRef::Enum16(b0, b1)) => *a1 == *b1 && *a0 == *b0,
             (ValueRef::DateTime64(this, this_params), ValueRef::DateTime64(that, that_params)) => {
-                let (this_precision, this_tz) = **this_params;
-                let (that_precision2, that_tz) = **that_params;
-
-                let this_time = to_datetime(*this, this_precision, this_tz);
-                let that_time = to_datetime(*that, that_precision2, that_tz);
+                let (this_precision, _) = **this_params;
+                let (that_precision, _) = **that_params;
 
-                this_time == that_time
+                datetime64_nanos(*this, this_precision) == datetime64_nanos(*that, that_precision)
             }
+            (ValueRef::Ipv4(a), ValueRef::Ipv4(b)) => *a == *b,
+            (ValueRef::Ipv6(a), ValueRef::Ipv6(b)) => *a == *b,
+            (ValueRef::Uuid(a), ValueRef::Uuid(b)) => *a == *b,
             _ => false,
         }
     }
 }
 
+impl<'a> Eq for ValueRef<'a> {}
+
+impl<'a> Hash for ValueRef<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            ValueRef::UInt8(v) => v.hash(state),
+            ValueRef::UInt16(v) => v.hash(state),
+            ValueRef::UInt32(v) => v.hash(state),
+            ValueRef::UInt64(v) => v.hash(state),
+            ValueRef::UInt128(v) => v.hash(state),
+            ValueRef::Int8(v) => v.hash(state),
+            ValueRef::Int16(v) => v.hash(state),
+            ValueRef::Int32(v) => v.hash(state),
+            ValueRef::Int64(v) => v.hash(state),
+            ValueRef::Int128(v) => v.hash(state),
+            ValueRef::String(v) => v.hash(state),
+            ValueRef::Float32(v) => float_bits(f64::from(*v)).hash(state),
+            ValueRef::Float64(v) => float_bits(*v).hash(state),
+            ValueRef::Date(v, _) => v.hash(state),
+            ValueRef::DateTime(v, _) => v.hash(state),
+            ValueRef::DateTime64(v, params) => datetime64_nanos(*v, params.0).hash(state),
+            ValueRef::Ipv4(v) => v.hash(state),
+            ValueRef::Ipv6(v) => v.hash(state),
+            ValueRef::Uuid(v) => v.hash(state),
+            ValueRef::Nullable(Either::Left(_)) => {}
+            ValueRef::Nullable(Either::Right(v)) => v.hash(state),
+            ValueRef::Array(_, vs) => vs.hash(state),
+            ValueRef::Map(_, _, entries) => entries.hash(state),
+            ValueRef::Decimal(v) => v.hash(state),
+            ValueRef::Enum8(_, v) => v.internal().hash(state),
+            ValueRef::Enum16(_, v) => v.internal().hash(state),
+        }
+    }
+}
+
 impl<'a> fmt::Display for ValueRef<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -92,10 +146,12 @@ impl<'a> fmt::Display for ValueRef<'a> {
             ValueRef::UInt16(v) => fmt::Display::fmt(v, f),
             ValueRef::UInt32(v) => fmt::Display::fmt(v, f),
             ValueRef::UInt64(v) => fmt::Display::fmt(v, f),
+            ValueRef::UInt128(v) => fmt::Display::fmt(v, f),
             ValueRef::Int8(v) => fmt::Display::fmt(v, f),
             ValueRef::Int16(v) => fmt::Display::fmt(v, f),
             ValueRef::Int32(v) => fmt::Display::fmt(v, f),
             ValueRef::Int64(v) => fmt::Display::fmt(v, f),
+            ValueRef::Int128(v) => fmt::Display::fmt(v, f),
             ValueRef::String(v) => match str::from_utf8(v) {
                 Ok(s) => fmt::Display::fmt(s, f),
                 Err(_) => write!(f, "{:?}", *v),
@@ -133,6 +189,11 @@ impl<'a> fmt::Display for ValueRef<'a> {
                 let cells: Vec<String> = vs.iter().map(|v| format!("{}", v)).collect();
                 write!(f, "[{}]", cells.join(", "))
             }
+            ValueRef::Map(_, _, entries) => {
+                let cells: Vec<String> =
+                    entries.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{{{}}}", cells.join(", "))
+            }
             ValueRef::Decimal(v) => fmt::Display::fmt(v, f),
             ValueRef::Ipv4(v) => {
                 write!(f, "{}", Ipv4Addr::from(*v))
@@ -159,10 +220,12 @@ impl<'a> convert::From<ValueRef<'a>> for SqlType {
             ValueRef::UInt16(_) => SqlType::UInt16,
             ValueRef::UInt32(_) => SqlType::UInt32,
             ValueRef::UInt64(_) => SqlType::UInt64,
+            ValueRef::UInt128(_) => SqlType::UInt128,
             ValueRef::Int8(_) => SqlType::Int8,
             ValueRef::Int16(_) => SqlType::Int16,
             ValueRef::Int32(_) => SqlType::Int32,
             ValueRef::Int64(_) => SqlType::Int64,
+            ValueRef::Int128(_) => SqlType::Int128,
             ValueRef::String(_) => SqlType::String,
             ValueRef::Float32(_) => SqlType::Float32,
             ValueRef::Float64(_) => SqlType::Float64,
@@ -173,6 +236,7 @@ impl<'a> convert::From<ValueRef<'a>> for SqlType {
                 Either::Right(value_ref) => SqlType::Nullable(SqlType::from(*value_ref).into()),
             },
             ValueRef::Array(t, _) => SqlType::Array(t),
+            ValueRef::Map(key, value, _) => SqlType::Map(key, value),
             ValueRef::Decimal(v) => SqlType::Decimal(v.precision, v.scale),
             ValueRef::Enum8(values, _) => SqlType::Enum8(values),
             ValueRef::Enum16(values, _) => SqlType::Enum16(values),
@@ -214,6 +278,264 @@ impl<'a> ValueRef<'a> {
             dst: "&[u8]".into(),
         }))
     }
+
+    pub fn as_decimal(&self) -> Result<Decimal> {
+        if let ValueRef::Decimal(v) = self {
+            return Ok(v.clone());
+        }
+        let from = SqlType::from(self.clone()).to_string();
+        Err(Error::FromSql(FromSqlError::InvalidType {
+            src: from,
+            dst: "Decimal".into(),
+        }))
+    }
+
+    pub fn as_uuid(&self) -> Result<Uuid> {
+        if let ValueRef::Uuid(v) = self {
+            return Ok(Uuid::from_bytes(*v));
+        }
+        let from = SqlType::from(self.clone()).to_string();
+        Err(Error::FromSql(FromSqlError::InvalidType {
+            src: from,
+            dst: "Uuid".into(),
+        }))
+    }
+
+    pub fn as_ipv4(&self) -> Result<Ipv4Addr> {
+        if let ValueRef::Ipv4(v) = self {
+            return Ok(Ipv4Addr::from(*v));
+        }
+        let from = SqlType::from(self.clone()).to_string();
+        Err(Error::FromSql(FromSqlError::InvalidType {
+            src: from,
+            dst: "Ipv4".into(),
+        }))
+    }
+
+    pub fn as_ipv6(&self) -> Result<Ipv6Addr> {
+        if let ValueRef::Ipv6(v) = self {
+            return Ok(Ipv6Addr::from(*v));
+        }
+        let from = SqlType::from(self.clone()).to_string();
+        Err(Error::FromSql(FromSqlError::InvalidType {
+            src: from,
+            dst: "Ipv6".into(),
+        }))
+    }
+
+    /// Converts any integer value to `u64`, failing with `FromSqlError::OutOfRange` if
+    /// it doesn't fit (e.g. a negative `Int64`).
+    pub fn as_u64_lossless(&self) -> Result<u64> {
+        let value = self.as_i128_integer("u64")?;
+        u64::try_from(value).map_err(|_| Error::FromSql(FromSqlError::OutOfRange))
+    }
+
+    /// Converts any integer value to `i64`, failing with `FromSqlError::OutOfRange` if
+    /// it doesn't fit (e.g. a `UInt64` above `i64::MAX`).
+    pub fn as_i64_lossless(&self) -> Result<i64> {
+        let value = self.as_i128_integer("i64")?;
+        i64::try_from(value).map_err(|_| Error::FromSql(FromSqlError::OutOfRange))
+    }
+
+    // A `UInt128` above `i128::MAX` fits neither `u64` nor `i64`, so it's out of range.
+    fn as_i128_integer(&self, dst: &'static str) -> Result<i128> {
+        match self {
+            ValueRef::UInt8(v) => Ok(i128::from(*v)),
+            ValueRef::UInt16(v) => Ok(i128::from(*v)),
+            ValueRef::UInt32(v) => Ok(i128::from(*v)),
+            ValueRef::UInt64(v) => Ok(i128::from(*v)),
+            ValueRef::UInt128(v) => {
+                i128::try_from(*v).map_err(|_| Error::FromSql(FromSqlError::OutOfRange))
+            }
+            ValueRef::Int8(v) => Ok(i128::from(*v)),
+            ValueRef::Int16(v) => Ok(i128::from(*v)),
+            ValueRef::Int32(v) => Ok(i128::from(*v)),
+            ValueRef::Int64(v) => Ok(i128::from(*v)),
+            ValueRef::Int128(v) => Ok(*v),
+            _ => {
+                let from = SqlType::from(self.clone()).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: dst.into(),
+                }))
+            }
+        }
+    }
+
+    /// Converts a numeric (integer, float or `Decimal`) value to `f64`, possibly losing
+    /// precision for large integers.
+    pub fn as_f64(&self) -> Result<f64> {
+        match self {
+            ValueRef::UInt8(v) => Ok(f64::from(*v)),
+            ValueRef::UInt16(v) => Ok(f64::from(*v)),
+            ValueRef::UInt32(v) => Ok(f64::from(*v)),
+            ValueRef::UInt64(v) => Ok(*v as f64),
+            ValueRef::UInt128(v) => Ok(*v as f64),
+            ValueRef::Int8(v) => Ok(f64::from(*v)),
+            ValueRef::Int16(v) => Ok(f64::from(*v)),
+            ValueRef::Int32(v) => Ok(f64::from(*v)),
+            ValueRef::Int64(v) => Ok(*v as f64),
+            ValueRef::Int128(v) => Ok(*v as f64),
+            ValueRef::Float32(v) => Ok(f64::from(*v)),
+            ValueRef::Float64(v) => Ok(*v),
+            ValueRef::Decimal(v) => Ok(v.clone().into()),
+            _ => {
+                let from = SqlType::from(self.clone()).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "f64".into(),
+                }))
+            }
+        }
+    }
+
+    /// Converts a `Decimal` value to `f64` rounded to `digits` fractional digits, see
+    /// `Decimal::to_f64_rounded`.
+    pub fn decimal_to_f64_rounded(&self, digits: u8, mode: RoundingMode) -> Result<f64> {
+        Ok(self.as_decimal()?.to_f64_rounded(digits, mode))
+    }
+
+    /// Returns the label of an `Enum8`/`Enum16` value.
+    pub fn as_enum_label(&self) -> Result<&str> {
+        let label = match self {
+            ValueRef::Enum8(values, v) => values
+                .iter()
+                .find(|(_, k)| *k == v.internal())
+                .map(|(name, _)| name.as_str())
+                .ok_or(i16::from(v.internal())),
+            ValueRef::Enum16(values, v) => values
+                .iter()
+                .find(|(_, k)| *k == v.internal())
+                .map(|(name, _)| name.as_str())
+                .ok_or(v.internal()),
+            _ => {
+                let from = SqlType::from(self.clone()).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "enum label".into(),
+                }));
+            }
+        };
+        label.map_err(|value| Error::FromSql(FromSqlError::UnknownEnumValue { value }))
+    }
+
+    /// Formats a `Date`/`DateTime`/`DateTime64` value in its column timezone using a
+    /// `chrono` strftime pattern.
+    pub fn format_datetime(&self, pattern: &str) -> Result<String> {
+        let mut result = String::new();
+        let written = match self {
+            ValueRef::Date(..) => {
+                let date = AppDate::from(self.clone());
+                write!(result, "{}", date.format(pattern))
+            }
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                let time = AppDateTime::from(self.clone());
+                write!(result, "{}", time.format(pattern))
+            }
+            _ => {
+                let from = SqlType::from(self.clone()).to_string();
+                return Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "DateTime".into(),
+                }));
+            }
+        };
+
+        match written {
+            Ok(()) => Ok(result),
+            Err(_) => Err(Error::FromSql(FromSqlError::InvalidFormat {
+                format: pattern.to_string(),
+            })),
+        }
+    }
+
+    /// Returns how long before `now` a `DateTime`/`DateTime64` value lies; the result
+    /// is negative for values after `now`.
+    pub fn age_from(&self, now: DateTime<Tz>) -> Result<chrono::Duration> {
+        match self {
+            ValueRef::DateTime(..) | ValueRef::DateTime64(..) => {
+                Ok(now.signed_duration_since(AppDateTime::from(self.clone())))
+            }
+            _ => {
+                let from = SqlType::from(self.clone()).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "DateTime".into(),
+                }))
+            }
+        }
+    }
+
+    /// Converts the value to JSON: numbers and strings map directly (128-bit integers
+    /// outside the 64-bit range become strings), enums become their label, NULL becomes
+    /// `null`, arrays and maps (as objects) recurse and everything else uses `Display`.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value as Json;
+
+        match self {
+            ValueRef::UInt8(v) => Json::from(*v),
+            ValueRef::UInt16(v) => Json::from(*v),
+            ValueRef::UInt32(v) => Json::from(*v),
+            ValueRef::UInt64(v) => Json::from(*v),
+            ValueRef::UInt128(v) => {
+                u64::try_from(*v).map_or_else(|_| Json::from(v.to_string()), Json::from)
+            }
+            ValueRef::Int8(v) => Json::from(*v),
+            ValueRef::Int16(v) => Json::from(*v),
+            ValueRef::Int32(v) => Json::from(*v),
+            ValueRef::Int64(v) => Json::from(*v),
+            ValueRef::Int128(v) => {
+                i64::try_from(*v).map_or_else(|_| Json::from(v.to_string()), Json::from)
+            }
+            ValueRef::Float32(v) => Json::from(f64::from(*v)),
+            ValueRef::Float64(v) => Json::from(*v),
+            ValueRef::String(v) => match str::from_utf8(v) {
+                Ok(s) => Json::from(s),
+                Err(_) => Json::from(v.to_vec()),
+            },
+            ValueRef::Enum8(..) | ValueRef::Enum16(..) => match self.as_enum_label() {
+                Ok(label) => Json::from(label),
+                Err(_) => Json::from(self.to_string()),
+            },
+            ValueRef::Nullable(Either::Left(_)) => Json::Null,
+            ValueRef::Nullable(Either::Right(v)) => v.to_json(),
+            ValueRef::Array(_, vs) => Json::Array(vs.iter().map(ValueRef::to_json).collect()),
+            ValueRef::Map(_, _, entries) => Json::Object(
+                entries
+                    .iter()
+                    .map(|(k, v)| {
+                        let key = k.as_str().map_or_else(|_| k.to_string(), str::to_owned);
+                        (key, v.to_json())
+                    })
+                    .collect(),
+            ),
+            _ => Json::from(self.to_string()),
+        }
+    }
+
+    /// Folds the elements of an array value without collecting them.
+    pub fn try_fold<T, F>(self, init: T, mut f: F) -> Result<T>
+    where
+        F: FnMut(T, ValueRef<'a>) -> Result<T>,
+    {
+        match self {
+            ValueRef::Array(_, vs) => {
+                let mut acc = init;
+                for v in vs.iter() {
+                    acc = f(acc, v.clone())?;
+                }
+                Ok(acc)
+            }
+            _ => {
+                let from = SqlType::from(self).to_string();
+                Err(Error::FromSql(FromSqlError::InvalidType {
+                    src: from,
+                    dst: "Array".into(),
+                }))
+            }
+        }
+    }
 }
 
 impl<'a> From<ValueRef<'a>> for Value {
@@ -223,10 +545,12 @@ impl<'a> From<ValueRef<'a>> for Value {
             ValueRef::UInt16(v) => Value::UInt16(v),
             ValueRef::UInt32(v) => Value::UInt32(v),
             ValueRef::UInt64(v) => Value::UInt64(v),
+            ValueRef::UInt128(v) => Value::UInt128(v),
             ValueRef::Int8(v) => Value::Int8(v),
             ValueRef::Int16(v) => Value::Int16(v),
             ValueRef::Int32(v) => Value::Int32(v),
             ValueRef::Int64(v) => Value::Int64(v),
+            ValueRef::Int128(v) => Value::Int128(v),
             ValueRef::String(v) => Value::String(Arc::new(v.into())),
             ValueRef::Float32(v) => Value::Float32(v),
             ValueRef::Float64(v) => Value::Float64(v),
@@ -247,6 +571,13 @@ impl<'a> From<ValueRef<'a>> for Value {
                 }
                 Value::Array(t, Arc::new(value_list))
             }
+            ValueRef::Map(key, value, entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(k, v)| (k.clone().into(), v.clone().into()))
+                    .collect();
+                Value::Map(key, value, Arc::new(entries))
+            }
             ValueRef::Decimal(v) => Value::Decimal(v),
             ValueRef::Enum8(e_v, v) => Value::Enum8(e_v, v),
             ValueRef::Enum16(e_v, v) => Value::Enum16(e_v, v),
@@ -287,11 +618,13 @@ from_number! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     i8: Int8,
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
 
     f32: Float32,
     f64: Float64
@@ -304,10 +637,12 @@ impl<'a> From<&'a Value> for ValueRef<'a> {
             Value::UInt16(v) => ValueRef::UInt16(*v),
             Value::UInt32(v) => ValueRef::UInt32(*v),
             Value::UInt64(v) => ValueRef::UInt64(*v),
+            Value::UInt128(v) => ValueRef::UInt128(*v),
             Value::Int8(v) => ValueRef::Int8(*v),
             Value::Int16(v) => ValueRef::Int16(*v),
             Value::Int32(v) => ValueRef::Int32(*v),
             Value::Int64(v) => ValueRef::Int64(*v),
+            Value::Int128(v) => ValueRef::Int128(*v),
             Value::String(v) => ValueRef::String(v),
             Value::Float32(v) => ValueRef::Float32(*v),
             Value::Float64(v) => ValueRef::Float64(*v),
@@ -329,6 +664,10 @@ impl<'a> From<&'a Value> for ValueRef<'a> {
                 }
                 ValueRef::Array(*t, Arc::new(ref_vec))
             }
+            Value::Map(key, value, entries) => {
+                let entries = entries.iter().map(|(k, v)| (k.into(), v.into())).collect();
+                ValueRef::Map(key, value, Arc::new(entries))
+            }
             Value::Decimal(v) => ValueRef::Decimal(v.clone()),
             Value::Enum8(values, v) => ValueRef::Enum8(values.to_vec(), *v),
             Value::Enum16(values, v) => ValueRef::Enum16(values.to_vec(), *v),
@@ -388,11 +727,13 @@ value_from! {
     u16: UInt16,
     u32: UInt32,
     u64: UInt64,
+    u128: UInt128,
 
     i8: Int8,
     i16: Int16,
     i32: Int32,
     i64: Int64,
+    i128: Int128,
 
     f32: Float32,
     f64: Float64
@@ -400,6 +741,8 @@ value_from! {
 
 #[cfg(test)]
 mod test {
+    use std::collections::{hash_map::DefaultHasher, HashSet};
+
     use super::*;
 
     #[test]
@@ -584,4 +927,213 @@ mod test {
             SqlType::Nullable(SqlType::Int8.into())
         );
     }
+
+    #[test]
+    fn test_as_f64() {
+        assert_eq!(ValueRef::UInt8(3).as_f64().unwrap(), 3.0);
+        assert_eq!(ValueRef::Int64(-2).as_f64().unwrap(), -2.0);
+        assert_eq!(
+            ValueRef::Decimal(Decimal::of(1.25_f64, 2)).as_f64().unwrap(),
+            1.25
+        );
+        assert!(ValueRef::String(b"1").as_f64().is_err());
+    }
+
+    #[test]
+    fn test_as_u64_lossless() {
+        assert_eq!(ValueRef::UInt8(42).as_u64_lossless().unwrap(), 42);
+        assert_eq!(ValueRef::Int128(7).as_u64_lossless().unwrap(), 7);
+
+        match ValueRef::Int64(-1).as_u64_lossless() {
+            Err(Error::FromSql(FromSqlError::OutOfRange)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(ValueRef::UInt128(u128::MAX).as_u64_lossless().is_err());
+        assert!(ValueRef::Float64(1.0).as_u64_lossless().is_err());
+    }
+
+    #[test]
+    fn test_as_i64_lossless() {
+        assert_eq!(ValueRef::Int64(-1).as_i64_lossless().unwrap(), -1);
+        assert_eq!(
+            ValueRef::UInt32(u32::MAX).as_i64_lossless().unwrap(),
+            4_294_967_295
+        );
+
+        match ValueRef::UInt64(u64::MAX).as_i64_lossless() {
+            Err(Error::FromSql(FromSqlError::OutOfRange)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(ValueRef::String(b"1").as_i64_lossless().is_err());
+    }
+
+    #[test]
+    fn test_as_decimal() {
+        let v = ValueRef::Decimal(Decimal::of(1.25_f64, 2));
+        assert_eq!(v.as_decimal().unwrap(), Decimal::of(1.25_f64, 2));
+
+        match ValueRef::Float64(1.25).as_decimal() {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("Float64", "Decimal"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_as_uuid() {
+        let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+        assert_eq!(ValueRef::Uuid(*uuid.as_bytes()).as_uuid().unwrap(), uuid);
+
+        match ValueRef::String(b"936da01f").as_uuid() {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("String", "Uuid"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_as_ipv4() {
+        let v = ValueRef::Ipv4([192, 168, 0, 1]);
+        assert_eq!(v.as_ipv4().unwrap(), Ipv4Addr::new(192, 168, 0, 1));
+
+        match ValueRef::Ipv6([0; 16]).as_ipv4() {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("IPv6", "Ipv4"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_as_ipv6() {
+        let v = ValueRef::Ipv6([0; 16]);
+        assert_eq!(v.as_ipv6().unwrap(), Ipv6Addr::UNSPECIFIED);
+
+        match ValueRef::UInt32(1).as_ipv6() {
+            Err(Error::FromSql(FromSqlError::InvalidType { src, dst })) => {
+                assert_eq!((src.as_ref(), dst.as_ref()), ("UInt32", "Ipv6"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_f64_rounded() {
+        let v = ValueRef::Decimal(Decimal::of(0.125_f64, 3));
+        let half_even = v.decimal_to_f64_rounded(2, RoundingMode::HalfEven).unwrap();
+        let half_up = v.decimal_to_f64_rounded(2, RoundingMode::HalfUp).unwrap();
+        assert_eq!((half_even, half_up), (0.12, 0.13));
+
+        let v = ValueRef::Float64(0.125);
+        assert!(v.decimal_to_f64_rounded(2, RoundingMode::HalfEven).is_err());
+    }
+
+    #[test]
+    fn test_try_fold() {
+        let v = ValueRef::Array(
+            SqlType::UInt32.into(),
+            Arc::new(vec![
+                ValueRef::UInt32(1),
+                ValueRef::UInt32(2),
+                ValueRef::UInt32(3),
+            ]),
+        );
+
+        let sum = v
+            .try_fold(0_u64, |acc, item| Ok(acc + u64::from(u32::from(item))))
+            .unwrap();
+        assert_eq!(sum, 6);
+
+        assert!(ValueRef::UInt32(1).try_fold(0, |acc, _| Ok(acc)).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        set.insert(ValueRef::UInt8(1));
+        set.insert(ValueRef::UInt8(1));
+        set.insert(ValueRef::String(b"a"));
+        set.insert(ValueRef::Float64(0.0));
+        set.insert(ValueRef::Float64(-0.0));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_float_eq_and_hash() {
+        fn hash_of(value: &ValueRef) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let nan = ValueRef::Float64(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(hash_of(&nan), hash_of(&nan.clone()));
+        assert_eq!(ValueRef::Float32(f32::NAN), ValueRef::Float32(f32::NAN));
+
+        let (zero, neg_zero) = (ValueRef::Float64(0.0), ValueRef::Float64(-0.0));
+        assert_eq!(zero, neg_zero);
+        assert_eq!(hash_of(&zero), hash_of(&neg_zero));
+        assert_ne!(zero, ValueRef::Float64(1.0));
+
+        let mut set = HashSet::new();
+        set.insert(ValueRef::Float64(f64::NAN));
+        set.insert(ValueRef::Float64(f64::NAN));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_date_eq_and_hash_ignore_tz() {
+        fn hash_of(value: &ValueRef) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let utc = ValueRef::Date(18628, Tz::UTC);
+        let new_york = ValueRef::Date(18628, Tz::America__New_York);
+        assert_eq!(utc, new_york);
+        assert_eq!(hash_of(&utc), hash_of(&new_york));
+        assert_eq!(Value::from(utc.clone()), Value::from(new_york.clone()));
+        assert_ne!(utc, ValueRef::Date(18629, Tz::UTC));
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let v = ValueRef::DateTime(1_735_515_000, Tz::UTC);
+        assert_eq!(
+            v.format_datetime("%Y-%m-%d %H:%M").unwrap(),
+            "2024-12-29 23:30"
+        );
+
+        let v = ValueRef::DateTime(1_735_515_000, Tz::Asia__Tokyo);
+        assert_eq!(
+            v.format_datetime("%Y-%m-%d %H:%M").unwrap(),
+            "2024-12-30 08:30"
+        );
+
+        let v = ValueRef::Date(18628, Tz::UTC);
+        assert_eq!(v.format_datetime("%d.%m.%Y").unwrap(), "01.01.2021");
+
+        match v.format_datetime("%Q") {
+            Err(Error::FromSql(FromSqlError::InvalidFormat { format })) => assert_eq!(format, "%Q"),
+            _ => panic!("should fail"),
+        }
+        assert!(ValueRef::UInt32(1).format_datetime("%Y").is_err());
+    }
+
+    #[test]
+    fn test_age_from() {
+        let now = Tz::UTC.timestamp_opt(1_735_515_000, 0).unwrap();
+
+        let v = ValueRef::DateTime(1_735_515_000 - 3600, Tz::UTC);
+        assert_eq!(v.age_from(now).unwrap(), chrono::Duration::hours(1));
+
+        let v = ValueRef::DateTime(1_735_515_000 + 60, Tz::Asia__Tokyo);
+        assert_eq!(v.age_from(now).unwrap(), chrono::Duration::minutes(-1));
+
+        assert!(ValueRef::Date(18628, Tz::UTC).age_from(now).is_err());
+    }
 }
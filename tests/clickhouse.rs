@@ -1038,7 +1038,7 @@ async fn test_decimal() -> Result<(), Error> {
     let ox0: Option<Decimal> = block.get(0, "ox")?;
 
     assert_eq!(2, block.row_count());
-    assert_eq!(1.234, x.into());
+    assert_eq!(1.234, f64::from(x));
     assert_eq!(Some(1.23), ox.map(|v| v.into()));
     assert_eq!(None, ox0);
 